@@ -5,13 +5,17 @@ use std::{
 };
 
 use lum_libs::parking_lot::Mutex;
+#[cfg(feature = "serde")]
+use lum_libs::serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{Event, subscriber::DispatchError};
+use crate::{
+    Event, event::SubscribeError, observable::SubscribedValue, subscriber::DispatchFailure,
+};
 
 #[derive(Debug)]
 pub enum Result<T> {
     Unchanged,
-    Changed(CoreResult<(), Vec<DispatchError<Arc<T>>>>),
+    Changed(CoreResult<(), Vec<DispatchFailure<Arc<T>>>>),
 }
 
 #[derive(Debug)]
@@ -33,6 +37,32 @@ impl<T: Send + Sync + Hash> ArcObservable<T> {
         self.value.lock().clone()
     }
 
+    // Drops the event along with its subscribers. The value may still be shared with earlier
+    // dispatches, so it stays behind its Arc.
+    pub fn into_inner(self) -> Arc<T> {
+        self.value.into_inner()
+    }
+
+    // The value is read and the subscription created under the same lock, so no change between
+    // the two can be missed
+    pub fn get_and_subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<SubscribedValue<Arc<T>>, SubscribeError>
+    where
+        T: 'static,
+    {
+        let current_value = self.value.lock();
+        let (id, receiver) =
+            self.on_change
+                .subscribe_channel(name, buffer, log_on_error, remove_on_error)?;
+
+        Ok((current_value.clone(), id, receiver))
+    }
+
     //TODO: Docs about cancelation safety. value can be dropped without reaching a channel.
     pub async fn set(&self, value: T) -> Result<T> {
         let mut hasher = DefaultHasher::new();
@@ -62,6 +92,13 @@ impl<T: Send + Sync + Hash> ArcObservable<T> {
     }
 }
 
+// The event is named after the value's type
+impl<T: Send + Sync + Hash + Default> Default for ArcObservable<T> {
+    fn default() -> Self {
+        Self::new(T::default(), std::any::type_name::<T>())
+    }
+}
+
 impl<T: Send + Sync + Hash> AsRef<Event<Arc<T>>> for ArcObservable<T> {
     fn as_ref(&self) -> &Event<Arc<T>> {
         &self.on_change
@@ -104,3 +141,31 @@ impl<T: Send + Sync + Hash> PartialEq<T> for ArcObservable<T> {
 }
 
 impl<T: Send + Sync + Hash> Eq for ArcObservable<T> {}
+
+#[cfg(feature = "serde")]
+impl<T: Send + Sync + Hash + Serialize> Serialize for ArcObservable<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> CoreResult<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+// Like Observable, the event is named after the value's type unless deserialize_named is used
+#[cfg(feature = "serde")]
+impl<'de, T: Send + Sync + Hash + Deserialize<'de>> Deserialize<'de> for ArcObservable<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> CoreResult<Self, D::Error> {
+        Self::deserialize_named(deserializer, std::any::type_name::<T>())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Send + Sync + Hash> ArcObservable<T> {
+    pub fn deserialize_named<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        event_name: impl Into<String>,
+    ) -> CoreResult<Self, D::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        T::deserialize(deserializer).map(|value| Self::new(value, event_name))
+    }
+}
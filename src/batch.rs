@@ -0,0 +1,140 @@
+use std::{fmt::Display, sync::Arc, time::Duration};
+
+use lum_boxtypes::PinnedBoxedFuture;
+use lum_libs::tokio::{sync::mpsc::Receiver, time::Instant};
+use lum_log::error;
+
+use crate::runtime::{Runtime, timeout_at};
+
+pub type BatchClosure<T, E> = dyn Fn(Vec<T>) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync;
+
+// A batch is delivered once it holds max_items payloads or max_delay passed since its first payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Batch {
+    pub max_items: usize,
+    pub max_delay: Duration,
+}
+
+impl Batch {
+    pub fn new(max_items: usize, max_delay: Duration) -> Self {
+        Self {
+            max_items,
+            max_delay,
+        }
+    }
+}
+
+pub(crate) struct BatchLoop<T, E> {
+    pub name: String,
+    pub batch: Batch,
+    pub closure: Box<BatchClosure<T, E>>,
+    pub log_on_error: bool,
+    pub remove_on_error: bool,
+}
+
+impl<T: Send, E: Display> BatchLoop<T, E> {
+    // Ends once the subscriber is gone, after delivering what was left. Stopping early because of
+    // remove_on_error closes the channel, which removes the subscriber with the next dispatch.
+    pub async fn run(self, runtime: Arc<dyn Runtime>, mut receiver: Receiver<T>) {
+        while let Some(first) = receiver.recv().await {
+            let deadline = Instant::now() + self.batch.max_delay;
+            let mut items = vec![first];
+            let mut closed = false;
+
+            while items.len() < self.batch.max_items {
+                match timeout_at(&*runtime, deadline, receiver.recv()).await {
+                    Some(Some(data)) => items.push(data),
+                    Some(None) => {
+                        closed = true;
+                        break;
+                    }
+                    None => break,
+                }
+            }
+
+            if let Err(err) = (self.closure)(items).await {
+                if self.log_on_error {
+                    error!(
+                        "Batching subscriber \"{}\" failed to handle a batch: {}.",
+                        self.name, err
+                    );
+                }
+
+                if self.remove_on_error {
+                    return;
+                }
+            }
+
+            if closed {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio::{self, sync::mpsc::unbounded_channel};
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+    const MAX_DELAY: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn deliver_full_and_timed_out_batches() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (sender, mut receiver) = unbounded_channel();
+        event
+            .subscribe_batched(
+                SUBSCRIBER_NAME,
+                Batch::new(3, MAX_DELAY),
+                move |batch| {
+                    let _ = sender.send(batch);
+                    Box::pin(async { Ok(()) })
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        for data in 1..=4 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        assert_eq!(receiver.recv().await.unwrap(), [1, 2, 3]);
+
+        let start = Instant::now();
+        assert_eq!(receiver.recv().await.unwrap(), [4]);
+        assert!(start.elapsed() >= MAX_DELAY / 2);
+    }
+
+    #[tokio::test]
+    async fn flush_remaining_items_on_unsubscribe() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let delivered = Arc::new(AtomicUsize::new(0));
+        let closure_delivered = delivered.clone();
+        let id = event
+            .subscribe_batched(
+                SUBSCRIBER_NAME,
+                Batch::new(10, Duration::from_secs(60)),
+                move |batch| {
+                    closure_delivered.fetch_add(batch.len(), Ordering::Relaxed);
+                    Box::pin(async { Ok(()) })
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        event.dispatch(1).await.unwrap();
+        event.dispatch(2).await.unwrap();
+        event.unsubscribe(id);
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(delivered.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+
+use lum_libs::tokio::{sync::mpsc::UnboundedReceiver, time::Instant};
+
+use crate::{
+    Event,
+    event::EventHandle,
+    relay::{Relay, RelayError},
+    runtime::{Runtime, TokioRuntime, timeout_at},
+};
+
+pub struct CoalescingEvent<T: Clone + Send + 'static> {
+    pub event: Event<T>,
+
+    window: Duration,
+    relay: Relay<T>,
+}
+
+impl<T: Clone + Send + 'static> CoalescingEvent<T> {
+    pub fn new(name: impl Into<String>, window: Duration) -> Self {
+        Self::new_with_merge(name, window, |_, latest| latest)
+    }
+
+    pub fn new_with_runtime(
+        name: impl Into<String>,
+        window: Duration,
+        runtime: impl Runtime,
+    ) -> Self {
+        Self::new_with_merge_and_runtime(name, window, |_, latest| latest, runtime)
+    }
+
+    pub fn new_with_merge(
+        name: impl Into<String>,
+        window: Duration,
+        merge: impl Fn(T, T) -> T + Send + 'static,
+    ) -> Self {
+        Self::new_with_merge_and_runtime(name, window, merge, TokioRuntime)
+    }
+
+    pub fn new_with_merge_and_runtime(
+        name: impl Into<String>,
+        window: Duration,
+        merge: impl Fn(T, T) -> T + Send + 'static,
+        runtime: impl Runtime,
+    ) -> Self {
+        let event = Event::builder(name).runtime(runtime).build();
+        let relay = Relay::spawn(
+            &event,
+            move |runtime, event_handle, event_queue_receiver| {
+                run_coalesce_loop(
+                    runtime,
+                    event_handle,
+                    event_queue_receiver,
+                    window,
+                    Box::new(merge),
+                )
+            },
+        );
+
+        Self {
+            event,
+            window,
+            relay,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.event.name()
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub fn dispatch(&self, data: T) -> Result<(), RelayError> {
+        self.relay.send(&self.event, data)
+    }
+}
+
+async fn run_coalesce_loop<T: Clone + Send + 'static>(
+    runtime: Arc<dyn Runtime>,
+    event_handle: EventHandle<T>,
+    mut event_queue_receiver: UnboundedReceiver<T>,
+    window: Duration,
+    merge: Box<dyn Fn(T, T) -> T + Send + 'static>,
+) {
+    while let Some(mut data) = event_queue_receiver.recv().await {
+        let deadline = Instant::now() + window;
+        let mut queue_closed = false;
+
+        loop {
+            match timeout_at(&*runtime, deadline, event_queue_receiver.recv()).await {
+                Some(Some(next)) => data = merge(data, next),
+                Some(None) => {
+                    queue_closed = true;
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        if event_handle.dispatch(data).await.is_err() || queue_closed {
+            return;
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> AsRef<Event<T>> for CoalescingEvent<T> {
+    fn as_ref(&self) -> &Event<T> {
+        &self.event
+    }
+}
+
+impl<T: Clone + Send + 'static> Display for CoalescingEvent<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CoalescingEvent {} ({}ms window)",
+            self.event.name(),
+            self.window.as_millis()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio::{self, time::sleep};
+
+    const EVENT_NAME: &str = "test_coalescing_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+    const WINDOW: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn coalesce_to_latest_value() {
+        let coalescing_event = CoalescingEvent::new(EVENT_NAME, WINDOW);
+        let (_, mut receiver) = coalescing_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        for i in 0..100u32 {
+            coalescing_event.dispatch(i).unwrap();
+        }
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received, 99);
+
+        sleep(WINDOW * 2).await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn coalesce_with_merge() {
+        let coalescing_event = CoalescingEvent::new_with_merge(EVENT_NAME, WINDOW, |a, b| a + b);
+        let (_, mut receiver) = coalescing_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        for i in 1..=10u32 {
+            coalescing_event.dispatch(i).unwrap();
+        }
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received, 55);
+    }
+
+    #[tokio::test]
+    async fn separate_windows_deliver_separately() {
+        let coalescing_event = CoalescingEvent::new(EVENT_NAME, WINDOW);
+        let (_, mut receiver) = coalescing_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        coalescing_event.dispatch(1u32).unwrap();
+        assert_eq!(receiver.recv().await.unwrap(), 1);
+
+        coalescing_event.dispatch(2u32).unwrap();
+        assert_eq!(receiver.recv().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn display() {
+        let coalescing_event = CoalescingEvent::<()>::new(EVENT_NAME, WINDOW);
+        assert_eq!(
+            coalescing_event.to_string(),
+            format!("CoalescingEvent {} (50ms window)", EVENT_NAME)
+        );
+    }
+}
@@ -0,0 +1,269 @@
+use std::{borrow::Borrow, collections::VecDeque, sync::Arc};
+
+use lum_libs::parking_lot::Mutex;
+
+use crate::{Event, Subscriber, event::SubscribeError, subscriber::Callback};
+
+impl<T: Clone + Send + 'static> Event<T> {
+    pub fn filtered(
+        &self,
+        predicate: impl Fn(&T) -> bool + Send + Sync + 'static,
+    ) -> Result<Event<T>, SubscribeError> {
+        let filtered = Event::new(format!("{} (filtered)", self.name()));
+        forward_into(self, &filtered, move |data| {
+            predicate(&data).then_some(data)
+        })?;
+
+        Ok(filtered)
+    }
+
+    pub fn mapped<U: Clone + Send + 'static>(
+        &self,
+        map: impl Fn(T) -> U + Send + Sync + 'static,
+    ) -> Result<Event<U>, SubscribeError> {
+        let mapped = Event::new(format!("{} (mapped)", self.name()));
+        forward_into(self, &mapped, move |data| Some(map(data)))?;
+
+        Ok(mapped)
+    }
+
+    pub fn merge(
+        sources: impl IntoIterator<Item = impl Borrow<Event<T>>>,
+    ) -> Result<Event<T>, SubscribeError> {
+        let sources: Vec<_> = sources.into_iter().collect();
+        let names: Vec<&str> = sources
+            .iter()
+            .map(|source| source.borrow().name())
+            .collect();
+
+        let merged = Event::new(format!("{} (merged)", names.join(" + ")));
+        for source in sources.iter() {
+            forward_into(source.borrow(), &merged, Some)?;
+        }
+
+        Ok(merged)
+    }
+}
+
+impl<A: Clone + Send + 'static, B: Clone + Send + 'static> Event<(A, B)> {
+    // Emits on every payload of either source once both have produced one
+    pub fn combine_latest(first: &Event<A>, second: &Event<B>) -> Result<Self, SubscribeError> {
+        let combined = Event::new(format!(
+            "{} + {} (combine_latest)",
+            first.name(),
+            second.name()
+        ));
+        let latest = Arc::new(Mutex::new((None::<A>, None::<B>)));
+
+        let first_latest = latest.clone();
+        forward_into(first, &combined, move |data| {
+            let mut latest = first_latest.lock();
+            latest.0 = Some(data.clone());
+            latest.1.clone().map(|second| (data, second))
+        })?;
+
+        forward_into(second, &combined, move |data| {
+            let mut latest = latest.lock();
+            latest.1 = Some(data.clone());
+            latest.0.clone().map(|first| (first, data))
+        })?;
+
+        Ok(combined)
+    }
+
+    // Pairs payloads by arrival order, unpaired payloads are buffered until the other source catches up
+    pub fn zip(first: &Event<A>, second: &Event<B>) -> Result<Self, SubscribeError> {
+        let zipped = Event::new(format!("{} + {} (zip)", first.name(), second.name()));
+        let pending = Arc::new(Mutex::new((VecDeque::<A>::new(), VecDeque::<B>::new())));
+
+        let first_pending = pending.clone();
+        forward_into(first, &zipped, move |data| {
+            let mut pending = first_pending.lock();
+            match pending.1.pop_front() {
+                Some(second) => Some((data, second)),
+                None => {
+                    pending.0.push_back(data);
+                    None
+                }
+            }
+        })?;
+
+        forward_into(second, &zipped, move |data| {
+            let mut pending = pending.lock();
+            match pending.0.pop_front() {
+                Some(first) => Some((first, data)),
+                None => {
+                    pending.1.push_back(data);
+                    None
+                }
+            }
+        })?;
+
+        Ok(zipped)
+    }
+}
+
+// The hidden subscription is owned by the target, so it is pruned once the target is dropped.
+// The target keeps the source alive in return, so intermediate events of a chain can be dropped.
+pub(crate) fn forward_into<S: Clone + Send + 'static, U: Clone + Send + 'static>(
+    source: &Event<S>,
+    target: &Event<U>,
+    forward: impl Fn(S) -> Option<U> + Send + Sync + 'static,
+) -> Result<u64, SubscribeError> {
+    let target_handle = target.handle();
+    let subscriber = Subscriber::new(
+        target.name(),
+        false,
+        true,
+        Callback::AsyncClosure(Box::new(move |data| {
+            let forwarded = forward(data);
+            let target_handle = target_handle.clone();
+
+            Box::pin(async move {
+                if let Some(data) = forwarded {
+                    target_handle.dispatch_report(data).await?;
+                }
+
+                Ok(())
+            })
+        })),
+    )
+    .with_owner(&target.inner);
+
+    let id = source.subscribe(subscriber)?;
+    target.upstream.lock().push(source.inner.clone());
+
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+
+    #[tokio::test]
+    async fn filtered_event() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let even = event.filtered(|data| data % 2 == 0).unwrap();
+        let (_, mut receiver) = even
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        for data in 1..=4 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, Some(4));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn mapped_event() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let mapped = event.mapped(|data| data.to_string()).unwrap();
+        let (_, mut receiver) = mapped
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        event.dispatch(42).await.unwrap();
+        assert_eq!(receiver.recv().await, Some("42".to_string()));
+    }
+
+    #[tokio::test]
+    async fn chain_filtered_and_mapped() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let pipeline = event
+            .filtered(|data| *data > 1)
+            .unwrap()
+            .mapped(|data| u16::from(data) * 100)
+            .unwrap();
+        let (_, mut receiver) = pipeline
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        event.dispatch(1).await.unwrap();
+        event.dispatch(2).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(200));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn merged_event() {
+        let first = Event::<u8>::new(EVENT_NAME);
+        let second = Event::<u8>::new(EVENT_NAME);
+        let merged = Event::merge([&first, &second]).unwrap();
+        let (_, mut receiver) = merged
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        first.dispatch(1).await.unwrap();
+        second.dispatch(2).await.unwrap();
+        first.dispatch(3).await.unwrap();
+
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, Some(3));
+
+        drop(merged);
+        first.dispatch(4).await.unwrap();
+        second.dispatch(4).await.unwrap();
+        assert_eq!(first.subscriber_count(), 0);
+        assert_eq!(second.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn combine_latest_event() {
+        let first = Event::<u8>::new(EVENT_NAME);
+        let second = Event::<char>::new(EVENT_NAME);
+        let combined = Event::combine_latest(&first, &second).unwrap();
+        let (_, mut receiver) = combined
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        first.dispatch(1).await.unwrap();
+        first.dispatch(2).await.unwrap();
+        second.dispatch('a').await.unwrap();
+        first.dispatch(3).await.unwrap();
+        second.dispatch('b').await.unwrap();
+
+        assert_eq!(receiver.recv().await, Some((2, 'a')));
+        assert_eq!(receiver.recv().await, Some((3, 'a')));
+        assert_eq!(receiver.recv().await, Some((3, 'b')));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn zip_event() {
+        let first = Event::<u8>::new(EVENT_NAME);
+        let second = Event::<char>::new(EVENT_NAME);
+        let zipped = Event::zip(&first, &second).unwrap();
+        let (_, mut receiver) = zipped
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        first.dispatch(1).await.unwrap();
+        first.dispatch(2).await.unwrap();
+        second.dispatch('a').await.unwrap();
+        second.dispatch('b').await.unwrap();
+        second.dispatch('c').await.unwrap();
+
+        assert_eq!(receiver.recv().await, Some((1, 'a')));
+        assert_eq!(receiver.recv().await, Some((2, 'b')));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn prune_hidden_subscription_of_dropped_derived_event() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let filtered = event.filtered(|_data| true).unwrap();
+        assert_eq!(event.subscriber_count(), 1);
+
+        drop(filtered);
+        event.dispatch(1).await.unwrap();
+        assert_eq!(event.subscriber_count(), 0);
+    }
+}
@@ -0,0 +1,128 @@
+use lum_boxtypes::{BoxedError, LifetimedPinnedBoxedFuture};
+
+use crate::subscriber::poll_once;
+
+// Extension point for delivery mechanisms the Callback enum doesn't cover, like ring buffers or device queues
+pub trait CustomCallback<T, E = BoxedError>: Send + Sync {
+    fn deliver<'a>(&'a self, data: T) -> LifetimedPinnedBoxedFuture<'a, Result<(), E>>
+    where
+        T: 'a;
+
+    // Used for replays, which can't wait. Returns None if the data couldn't be taken right away.
+    // By default the delivery has to finish on the first poll.
+    fn try_deliver(&self, data: T) -> Option<Result<(), E>> {
+        poll_once(self.deliver(data))
+    }
+
+    // Reported through Event::is_subscriber_alive
+    fn is_alive(&self) -> bool {
+        true
+    }
+
+    // Awaited by Event::flush
+    fn flush<'a>(&'a self) -> LifetimedPinnedBoxedFuture<'a, ()> {
+        Box::pin(async {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        fmt::{self, Display, Formatter},
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+    };
+
+    use super::*;
+    use crate::{EventBuilder, subscriber::DispatchError};
+    use lum_libs::{parking_lot::Mutex, tokio};
+
+    const EVENT_NAME: &str = "test_event";
+    const CALLBACK_NAME: &str = "test_callback";
+
+    #[derive(Debug, PartialEq)]
+    enum RingBufferError {
+        Closed,
+    }
+
+    impl Display for RingBufferError {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "The ring buffer is closed")
+        }
+    }
+
+    // Keeps the most recent values and drops the oldest when full
+    struct RingBuffer {
+        capacity: usize,
+        values: Arc<Mutex<VecDeque<u8>>>,
+        closed: Arc<AtomicBool>,
+    }
+
+    impl RingBuffer {
+        fn push(&self, data: u8) -> Result<(), RingBufferError> {
+            if !self.is_alive() {
+                return Err(RingBufferError::Closed);
+            }
+
+            let mut values = self.values.lock();
+            if values.len() == self.capacity {
+                values.pop_front();
+            }
+            values.push_back(data);
+
+            Ok(())
+        }
+    }
+
+    impl CustomCallback<u8, RingBufferError> for RingBuffer {
+        fn deliver<'a>(
+            &'a self,
+            data: u8,
+        ) -> LifetimedPinnedBoxedFuture<'a, Result<(), RingBufferError>>
+        where
+            u8: 'a,
+        {
+            Box::pin(async move { self.push(data) })
+        }
+
+        fn try_deliver(&self, data: u8) -> Option<Result<(), RingBufferError>> {
+            Some(self.push(data))
+        }
+
+        fn is_alive(&self) -> bool {
+            !self.closed.load(Ordering::Relaxed)
+        }
+    }
+
+    #[tokio::test]
+    async fn deliver_to_custom_callback() {
+        let event = EventBuilder::<u8, RingBufferError>::new(EVENT_NAME).build();
+        let values = Arc::new(Mutex::new(VecDeque::new()));
+        let closed = Arc::new(AtomicBool::new(false));
+        let ring_buffer = RingBuffer {
+            capacity: 2,
+            values: values.clone(),
+            closed: closed.clone(),
+        };
+
+        let id = event
+            .subscribe_custom(CALLBACK_NAME, ring_buffer, false, false)
+            .unwrap();
+        for data in 1..=3 {
+            event.dispatch(data).await.unwrap();
+        }
+        assert_eq!(*values.lock(), [2, 3]);
+
+        closed.store(true, Ordering::Relaxed);
+        assert!(!event.is_subscriber_alive(id));
+
+        let errors = event.dispatch(4).await.unwrap_err();
+        assert!(matches!(
+            errors[0].error,
+            DispatchError::Custom(RingBufferError::Closed)
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use lum_boxtypes::PinnedBoxedFuture;
+use lum_libs::tokio::sync::mpsc::UnboundedSender;
+use lum_log::error;
+
+use crate::{event::EventHandle, subscriber::SubscriberInfo};
+
+#[derive(Debug, Clone)]
+pub struct DeadLetter<T> {
+    pub event_id: u64,
+    pub event_name: String,
+    pub subscriber: SubscriberInfo,
+    pub error: String,
+    pub data: T,
+}
+
+pub enum DeadLetterSink<T: Clone + Send + 'static> {
+    Event(EventHandle<DeadLetter<T>>),
+    Channel(UnboundedSender<DeadLetter<T>>),
+}
+
+// Type-erased so EventInner<T> does not structurally contain EventInner<DeadLetter<T>>
+pub(crate) type DeadLetterForwarder<T> =
+    dyn Fn(DeadLetter<T>) -> PinnedBoxedFuture<()> + Send + Sync;
+
+impl<T: Clone + Send + 'static> DeadLetterSink<T> {
+    pub(crate) fn into_forwarder(self) -> Arc<DeadLetterForwarder<T>> {
+        match self {
+            DeadLetterSink::Event(event_handle) => Arc::new(move |dead_letter: DeadLetter<T>| {
+                let event_handle = event_handle.clone();
+                Box::pin(async move {
+                    let event_name = dead_letter.event_name.clone();
+                    if event_handle.dispatch(dead_letter).await.is_err() {
+                        error!(
+                            "Event \"{}\" failed to forward a dead letter because the dead letter event has been dropped.",
+                            event_name
+                        );
+                    }
+                })
+            }),
+            DeadLetterSink::Channel(sender) => Arc::new(move |dead_letter: DeadLetter<T>| {
+                let event_name = dead_letter.event_name.clone();
+                if sender.send(dead_letter).is_err() {
+                    error!(
+                        "Event \"{}\" failed to forward a dead letter because the dead letter channel is closed.",
+                        event_name
+                    );
+                }
+
+                Box::pin(async {})
+            }),
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> From<EventHandle<DeadLetter<T>>> for DeadLetterSink<T> {
+    fn from(event_handle: EventHandle<DeadLetter<T>>) -> Self {
+        DeadLetterSink::Event(event_handle)
+    }
+}
+
+impl<T: Clone + Send + 'static> From<UnboundedSender<DeadLetter<T>>> for DeadLetterSink<T> {
+    fn from(sender: UnboundedSender<DeadLetter<T>>) -> Self {
+        DeadLetterSink::Channel(sender)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio::{self, sync::mpsc::unbounded_channel};
+
+    const EVENT_NAME: &str = "test_event";
+    const DEAD_LETTER_EVENT_NAME: &str = "test_dead_letter_event";
+    const CLOSURE_NAME: &str = "test_closure";
+    const RECEIVER_NAME: &str = "test_receiver";
+
+    #[tokio::test]
+    async fn forward_failed_payload_to_channel() {
+        let event = Event::<u32>::new(EVENT_NAME);
+        let subscriber_id = event
+            .subscribe_closure(CLOSURE_NAME, |_data| Err("test_error".into()), false, false)
+            .unwrap();
+
+        let (sender, mut receiver) = unbounded_channel();
+        event.set_dead_letter_sink(sender);
+        assert!(event.has_dead_letter_sink());
+
+        assert!(event.dispatch(42).await.is_err());
+
+        let dead_letter = receiver.recv().await.unwrap();
+        assert_eq!(dead_letter.data, 42);
+        assert_eq!(dead_letter.event_id, event.id());
+        assert_eq!(dead_letter.event_name, EVENT_NAME);
+        assert_eq!(dead_letter.subscriber.id, subscriber_id);
+        assert!(dead_letter.error.contains("test_error"));
+    }
+
+    #[tokio::test]
+    async fn forward_failed_payload_to_event() {
+        let event = Event::<u32>::new(EVENT_NAME);
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Err("test_error".into()), false, false)
+            .unwrap();
+
+        let dead_letter_event = Event::<DeadLetter<u32>>::new(DEAD_LETTER_EVENT_NAME);
+        let (_, mut receiver) = dead_letter_event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+        event.set_dead_letter_sink(dead_letter_event.handle());
+
+        assert!(event.dispatch(7).await.is_err());
+        assert_eq!(receiver.recv().await.unwrap().data, 7);
+    }
+
+    #[tokio::test]
+    async fn successful_dispatch_creates_no_dead_letter() {
+        let event = Event::<u32>::new(EVENT_NAME);
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        let (sender, mut receiver) = unbounded_channel();
+        event.set_dead_letter_sink(sender);
+        event.dispatch(1).await.unwrap();
+        assert!(receiver.try_recv().is_err());
+
+        assert!(event.remove_dead_letter_sink());
+        assert!(!event.has_dead_letter_sink());
+    }
+}
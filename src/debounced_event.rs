@@ -0,0 +1,155 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+
+use lum_libs::tokio::{sync::mpsc::UnboundedReceiver, time::Instant};
+
+use crate::{
+    Event,
+    relay::{Relay, RelayError},
+    runtime::{Runtime, TokioRuntime, timeout_at},
+};
+
+pub struct DebouncedEvent<T: Clone + Send + 'static> {
+    pub event: Event<T>,
+
+    quiet_period: Duration,
+    relay: Relay<T>,
+}
+
+impl<T: Clone + Send + 'static> DebouncedEvent<T> {
+    pub fn new(name: impl Into<String>, quiet_period: Duration) -> Self {
+        Self::new_with_runtime(name, quiet_period, TokioRuntime)
+    }
+
+    pub fn new_with_runtime(
+        name: impl Into<String>,
+        quiet_period: Duration,
+        runtime: impl Runtime,
+    ) -> Self {
+        let event = Event::builder(name).runtime(runtime).build();
+        let relay = Relay::spawn(
+            &event,
+            move |runtime, event_handle, event_queue_receiver| {
+                run_debounce_loop(runtime, event_queue_receiver, quiet_period, move |data| {
+                    let event_handle = event_handle.clone();
+                    async move { event_handle.dispatch(data).await.is_ok() }
+                })
+            },
+        );
+
+        Self {
+            event,
+            quiet_period,
+            relay,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.event.name()
+    }
+
+    pub fn quiet_period(&self) -> Duration {
+        self.quiet_period
+    }
+
+    pub fn dispatch(&self, data: T) -> Result<(), RelayError> {
+        self.relay.send(&self.event, data)
+    }
+}
+
+// Delivers the last value once the queue has been quiet for the period, until the delivery fails.
+// A value that is still waiting when the queue closes is delivered right away.
+pub(crate) async fn run_debounce_loop<T, F, Fut>(
+    runtime: Arc<dyn Runtime>,
+    mut event_queue_receiver: UnboundedReceiver<T>,
+    quiet_period: Duration,
+    mut deliver: F,
+) where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    while let Some(mut data) = event_queue_receiver.recv().await {
+        let mut queue_closed = false;
+
+        loop {
+            let deadline = Instant::now() + quiet_period;
+            match timeout_at(&*runtime, deadline, event_queue_receiver.recv()).await {
+                Some(Some(next)) => data = next,
+                Some(None) => {
+                    queue_closed = true;
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        if !deliver(data).await || queue_closed {
+            return;
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> AsRef<Event<T>> for DebouncedEvent<T> {
+    fn as_ref(&self) -> &Event<T> {
+        &self.event
+    }
+}
+
+impl<T: Clone + Send + 'static> Display for DebouncedEvent<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DebouncedEvent {} ({}ms quiet period)",
+            self.event.name(),
+            self.quiet_period.as_millis()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio::{self, time::sleep};
+
+    const EVENT_NAME: &str = "test_debounced_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+    const QUIET_PERIOD: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn deliver_last_value_after_quiet_period() {
+        let debounced_event = DebouncedEvent::new(EVENT_NAME, QUIET_PERIOD);
+        let (_, mut receiver) = debounced_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        for i in 0..5u32 {
+            debounced_event.dispatch(i).unwrap();
+            sleep(QUIET_PERIOD / 5).await;
+        }
+
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(receiver.recv().await.unwrap(), 4);
+
+        sleep(QUIET_PERIOD * 2).await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn deliver_each_value_separated_by_quiet_period() {
+        let debounced_event = DebouncedEvent::new(EVENT_NAME, QUIET_PERIOD);
+        let (_, mut receiver) = debounced_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        debounced_event.dispatch(1u32).unwrap();
+        assert_eq!(receiver.recv().await.unwrap(), 1);
+
+        debounced_event.dispatch(2u32).unwrap();
+        assert_eq!(receiver.recv().await.unwrap(), 2);
+    }
+}
@@ -0,0 +1,187 @@
+use std::{error::Error, sync::Arc, time::Duration};
+
+use lum_boxtypes::BoxedError;
+use lum_libs::{parking_lot::Mutex, tokio::sync::mpsc::Receiver};
+
+use crate::{
+    Event, Observable, Subscriber,
+    event::SubscribeError,
+    observable::{Change, ChangeDetection},
+    subscriber::Callback,
+};
+
+// Read-only observable whose value is driven by a source. The source only holds a weak reference
+// to it, so the hidden subscription is pruned once the derived observable is dropped.
+#[derive(Debug)]
+pub struct DerivedObservable<T: Clone + Send> {
+    inner: Arc<Observable<T>>,
+}
+
+impl<T: Clone + Send + PartialEq + 'static> DerivedObservable<T> {
+    pub(crate) fn new(value: T, event_name: impl Into<String>) -> Self {
+        Self::with_change_detection(value, event_name, ChangeDetection::equality())
+    }
+}
+
+impl<T: Clone + Send + 'static> DerivedObservable<T> {
+    pub(crate) fn with_change_detection(
+        value: T,
+        event_name: impl Into<String>,
+        change_detection: ChangeDetection<T>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Observable::with_change_detection(
+                value,
+                event_name,
+                change_detection,
+            )),
+        }
+    }
+
+    // Sets the value to whatever forward returns for the source's payloads
+    pub(crate) fn follow<S: Clone + Send + 'static>(
+        &self,
+        source: &Event<S>,
+        forward: impl Fn(S) -> Option<T> + Send + Sync + 'static,
+    ) -> Result<u64, SubscribeError> {
+        self.inner.follow(source, forward)
+    }
+
+    // Routes the source's payloads through a relay like DebouncedEvent. The relay lives as long as
+    // the derived observable, so its task stops once the derived observable is dropped.
+    pub(crate) fn follow_relay<R, E>(
+        &self,
+        source: &Event<T>,
+        relay: R,
+        dispatch: fn(&R, T) -> Result<(), E>,
+    ) -> Result<u64, SubscribeError>
+    where
+        R: AsRef<Event<T>> + Send + Sync + 'static,
+        E: Error + Send + Sync + 'static,
+    {
+        let relay = Arc::new(relay);
+        self.follow(relay.as_ref().as_ref(), Some)?;
+
+        let weak_relay = Arc::downgrade(&relay);
+        let subscriber = Subscriber::new(
+            self.inner.on_change.name(),
+            false,
+            true,
+            Callback::Closure(Box::new(move |data| match weak_relay.upgrade() {
+                Some(relay) => dispatch(&relay, data).map_err(Into::into),
+                None => Ok(()),
+            })),
+        )
+        .with_owner(&self.inner);
+
+        self.inner.on_change.inner.upstream.lock().push(relay);
+        source.subscribe(subscriber)
+    }
+
+    // Takes the latest value of the source once per interval. The sampling task runs on the
+    // source's runtime and stops once the derived observable is dropped.
+    pub(crate) fn sample(
+        &self,
+        source: &Observable<T>,
+        current_value: T,
+        interval: Duration,
+    ) -> Result<u64, SubscribeError> {
+        let latest = Arc::new(Mutex::new(current_value));
+        let subscriber_latest = latest.clone();
+        let subscriber = Subscriber::new(
+            self.inner.on_change.name(),
+            false,
+            true,
+            Callback::Closure(Box::new(move |data| {
+                *subscriber_latest.lock() = data;
+                Ok(())
+            })),
+        )
+        .with_owner(&self.inner);
+        let id = source.on_change.subscribe(subscriber)?;
+
+        let target = Arc::downgrade(&self.inner);
+        let runtime = source.on_change.runtime().clone();
+        let task_runtime = runtime.clone();
+        runtime.spawn(Box::pin(async move {
+            loop {
+                task_runtime.sleep(interval).await;
+                let Some(target) = target.upgrade() else {
+                    return;
+                };
+
+                let value = latest.lock().clone();
+                // Failures are reported through the error handling of the derived subscribers
+                let _ = target.set(value).await;
+            }
+        }));
+
+        Ok(id)
+    }
+
+    pub fn get(&self) -> T {
+        self.inner.get()
+    }
+
+    pub fn name(&self) -> &str {
+        self.inner.on_change.name()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.inner.version()
+    }
+
+    // Resolves with the value after the next change
+    pub async fn changed(&self) -> T {
+        self.inner.changed().await
+    }
+
+    pub fn subscribe(&self, subscriber: Subscriber<T>) -> Result<u64, SubscribeError> {
+        self.inner.on_change.subscribe(subscriber)
+    }
+
+    pub fn subscribe_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), BoxedError> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        self.inner
+            .on_change
+            .subscribe_closure(name, closure, log_on_error, remove_on_error)
+    }
+
+    pub fn subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<(u64, Receiver<T>), SubscribeError> {
+        self.inner
+            .on_change
+            .subscribe_channel(name, buffer, log_on_error, remove_on_error)
+    }
+
+    pub fn unsubscribe(&self, id: u64) -> bool {
+        self.inner.on_change.unsubscribe(id)
+    }
+
+    pub fn subscribe_transition(
+        &self,
+        subscriber: Subscriber<Change<T>>,
+    ) -> Result<u64, SubscribeError> {
+        self.inner.on_transition.subscribe(subscriber)
+    }
+
+    pub fn unsubscribe_transition(&self, id: u64) -> bool {
+        self.inner.on_transition.unsubscribe(id)
+    }
+}
+
+impl<T: Clone + Send + PartialEq> PartialEq<T> for DerivedObservable<T> {
+    fn eq(&self, other: &T) -> bool {
+        *self.inner == *other
+    }
+}
@@ -0,0 +1,237 @@
+use lum_boxtypes::BoxedError;
+
+use crate::{
+    event::CancellableDispatchError,
+    subscriber::{DispatchError, DispatchFailure, SubscriberInfo},
+};
+
+#[derive(Debug)]
+pub enum DispatchOutcome<T, E = BoxedError> {
+    Delivered,
+    Failed(DispatchError<T, E>),
+    Removed(DispatchError<T, E>),
+    Skipped,
+    // The subscriber declined the payload through its filter or because it used up its calls
+    Filtered,
+}
+
+impl<T, E> DispatchOutcome<T, E> {
+    pub fn is_delivered(&self) -> bool {
+        matches!(self, DispatchOutcome::Delivered)
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            DispatchOutcome::Failed(_) | DispatchOutcome::Removed(_)
+        )
+    }
+
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, DispatchOutcome::Skipped)
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        matches!(self, DispatchOutcome::Filtered)
+    }
+
+    pub fn error(&self) -> Option<&DispatchError<T, E>> {
+        match self {
+            DispatchOutcome::Failed(error) | DispatchOutcome::Removed(error) => Some(error),
+            DispatchOutcome::Delivered | DispatchOutcome::Skipped | DispatchOutcome::Filtered => {
+                None
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SubscriberOutcome<T, E = BoxedError> {
+    pub subscriber: SubscriberInfo,
+    pub outcome: DispatchOutcome<T, E>,
+}
+
+#[derive(Debug)]
+pub struct DispatchReport<T, E = BoxedError> {
+    pub outcomes: Vec<SubscriberOutcome<T, E>>,
+    pub rate_limited: Option<T>,
+}
+
+impl<T, E> DispatchReport<T, E> {
+    pub(crate) fn new(outcomes: Vec<SubscriberOutcome<T, E>>) -> Self {
+        Self {
+            outcomes,
+            rate_limited: None,
+        }
+    }
+
+    pub(crate) fn new_rate_limited(data: T) -> Self {
+        Self {
+            outcomes: Vec::new(),
+            rate_limited: Some(data),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.rate_limited.is_none()
+            && self.outcomes.iter().all(|subscriber_outcome| {
+                subscriber_outcome.outcome.is_delivered()
+                    || subscriber_outcome.outcome.is_filtered()
+            })
+    }
+
+    pub fn delivered_count(&self) -> usize {
+        self.count(DispatchOutcome::is_delivered)
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.count(DispatchOutcome::is_error)
+    }
+
+    pub fn skipped_count(&self) -> usize {
+        self.count(DispatchOutcome::is_skipped)
+    }
+
+    pub fn filtered_count(&self) -> usize {
+        self.count(DispatchOutcome::is_filtered)
+    }
+
+    pub fn removed(&self) -> impl Iterator<Item = &SubscriberInfo> {
+        self.outcomes
+            .iter()
+            .filter(|subscriber_outcome| {
+                matches!(subscriber_outcome.outcome, DispatchOutcome::Removed(_))
+            })
+            .map(|subscriber_outcome| &subscriber_outcome.subscriber)
+    }
+
+    pub fn into_result(self) -> Result<(), Vec<DispatchFailure<T, E>>> {
+        let (errors, _) = self.into_errors_and_skipped();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+
+    pub fn into_cancellable_result(self) -> Result<(), CancellableDispatchError<T, E>> {
+        let (errors, skipped) = self.into_errors_and_skipped();
+        if !skipped.is_empty() {
+            return Err(CancellableDispatchError::Cancelled { skipped, errors });
+        }
+
+        if !errors.is_empty() {
+            return Err(CancellableDispatchError::Dispatch(errors));
+        }
+
+        Ok(())
+    }
+
+    fn count(&self, predicate: impl Fn(&DispatchOutcome<T, E>) -> bool) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|subscriber_outcome| predicate(&subscriber_outcome.outcome))
+            .count()
+    }
+
+    fn into_errors_and_skipped(self) -> (Vec<DispatchFailure<T, E>>, Vec<SubscriberInfo>) {
+        let mut errors = Vec::new();
+        let mut skipped = Vec::new();
+
+        if let Some(data) = self.rate_limited {
+            errors.push(DispatchFailure::without_subscriber(
+                DispatchError::RateLimited(data),
+            ));
+        }
+
+        for subscriber_outcome in self.outcomes.into_iter() {
+            match subscriber_outcome.outcome {
+                DispatchOutcome::Delivered | DispatchOutcome::Filtered => {}
+                DispatchOutcome::Failed(error) | DispatchOutcome::Removed(error) => {
+                    errors.push(DispatchFailure::new(subscriber_outcome.subscriber, error))
+                }
+                DispatchOutcome::Skipped => skipped.push(subscriber_outcome.subscriber),
+            }
+        }
+
+        (errors, skipped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Event,
+        rate_limit::{RateLimit, RateLimitPolicy},
+    };
+    use lum_libs::tokio;
+    use std::time::Duration;
+
+    const EVENT_NAME: &str = "test_event";
+    const OK_NAME: &str = "ok_closure";
+    const FAILING_NAME: &str = "failing_closure";
+    const REMOVED_NAME: &str = "removed_closure";
+
+    #[tokio::test]
+    async fn report_outcome_per_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let ok_id = event
+            .subscribe_closure(OK_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        let failing_id = event
+            .subscribe_closure(FAILING_NAME, |_data| Err("test_error".into()), false, false)
+            .unwrap();
+        let removed_id = event
+            .subscribe_closure(REMOVED_NAME, |_data| Err("test_error".into()), false, true)
+            .unwrap();
+
+        let report = event.dispatch_report(1).await;
+        assert!(!report.is_success());
+        assert_eq!(report.delivered_count(), 1);
+        assert_eq!(report.error_count(), 2);
+        assert_eq!(report.skipped_count(), 0);
+
+        for subscriber_outcome in report.outcomes.iter() {
+            let id = subscriber_outcome.subscriber.id;
+            match &subscriber_outcome.outcome {
+                DispatchOutcome::Delivered => assert_eq!(id, ok_id),
+                DispatchOutcome::Failed(_) => assert_eq!(id, failing_id),
+                DispatchOutcome::Removed(_) => assert_eq!(id, removed_id),
+                DispatchOutcome::Skipped | DispatchOutcome::Filtered => {
+                    panic!("No subscriber should have been skipped")
+                }
+            }
+        }
+
+        let removed: Vec<_> = report.removed().map(|subscriber| subscriber.id).collect();
+        assert_eq!(removed, vec![removed_id]);
+        assert_eq!(event.subscriber_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn report_skipped_subscribers_on_cancel() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event
+            .subscribe_closure(OK_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        let report = event.dispatch_report_with_cancel(1, async {}).await;
+        assert_eq!(report.skipped_count(), 1);
+        assert!(!report.is_success());
+    }
+
+    #[tokio::test]
+    async fn report_rate_limited_dispatch() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event.set_rate_limit(Some(RateLimit::new(
+            0,
+            Duration::from_secs(1),
+            RateLimitPolicy::Drop,
+        )));
+
+        let report = event.dispatch_report(5).await;
+        assert!(matches!(report.rate_limited, Some(5)));
+        assert!(!report.is_success());
+    }
+}
@@ -1,32 +1,90 @@
 use std::{
-    any::type_name,
+    any::{Any, type_name},
+    cmp::Reverse,
+    collections::VecDeque,
     fmt::{self, Debug, Display, Formatter},
+    future::{pending, poll_fn},
     hash::{Hash, Hasher},
     ops::Deref,
+    pin::{Pin, pin},
     sync::{Arc, Weak},
+    task::Poll,
+    time::Duration,
 };
 
-use lum_boxtypes::{BoxedError, PinnedBoxedFutureResult};
+use lum_boxtypes::{BoxedError, PinnedBoxedFuture};
 use lum_libs::{
     dashmap::DashMap,
-    tokio::sync::mpsc::{Receiver, channel},
+    parking_lot::{Mutex, MutexGuard, RwLock},
+    tokio::{
+        select,
+        sync::{
+            Semaphore,
+            mpsc::{Receiver, channel},
+            watch,
+        },
+        time::Instant,
+    },
 };
-use lum_log::error;
+use lum_log::{error, warn};
 use thiserror::Error;
 
+#[cfg(feature = "futures")]
+use futures_util::Sink;
+
+#[cfg(feature = "serde")]
+use crate::serialize_writer::{Framing, SerializeWriter, WriteError};
+#[cfg(feature = "serde")]
+use lum_libs::{serde::Serialize, tokio::io::AsyncWrite};
+
 use crate::{
-    Subscriber,
+    CustomCallback, EventHandler, Subscriber,
+    batch::{Batch, BatchLoop},
+    dead_letter::{DeadLetter, DeadLetterForwarder, DeadLetterSink},
+    dispatch_report::{DispatchOutcome, DispatchReport, SubscriberOutcome},
+    event_builder::{DispatchMode, EventBuilder, SubscribeDefaults},
     id::get_unique_id,
-    subscriber::{Callback, DispatchError},
+    rate_limit::{RateLimit, RateLimiter},
+    runtime::{Runtime, timeout_at},
+    scheduled_dispatch::ScheduledDispatch,
+    slow_consumer::{SlowConsumerDetection, SlowConsumerHandler, SlowConsumerWarning},
+    stats::{EventStats, StatsRecorder, SubscriberStats},
+    subscriber::{
+        Callback, CallbackError, DispatchError, DispatchFailure, HandlerFlow, SubscriberInfo,
+    },
+    subscription_guard::SubscriptionGuard,
+    trace::traced,
 };
 
-pub struct EventInner<T: Clone + Send> {
+pub type ErrorHandler<T, E = BoxedError> =
+    dyn Fn(&DispatchError<T, E>, &SubscriberInfo) + Send + Sync;
+
+pub struct EventInner<T: Clone + Send, E: CallbackError = BoxedError> {
     id: u64,
     name: String,
-    subscribers: DashMap<u64, Subscriber<T>>,
+    subscribers: DashMap<u64, Arc<Subscriber<T, E>>>,
+    rate_limiter: RwLock<Option<Arc<RateLimiter>>>,
+    error_handler: RwLock<Option<Arc<ErrorHandler<T, E>>>>,
+    dead_letter_forwarder: RwLock<Option<Arc<DeadLetterForwarder<T>>>>,
+    slow_consumer_detection: Option<SlowConsumerDetection>,
+    slow_consumer_handler: RwLock<Option<Arc<SlowConsumerHandler>>>,
+    max_subscribers: Mutex<Option<usize>>,
+    subscribe_defaults: SubscribeDefaults,
+    dispatch_mode: DispatchMode,
+    max_concurrency: Option<usize>,
+    concurrency_limit: Option<Semaphore>,
+    ordered: bool,
+    replay_capacity: usize,
+    replay: Mutex<VecDeque<T>>,
+    metrics: bool,
+    subscriber_count_sender: watch::Sender<usize>,
+    runtime: Arc<dyn Runtime>,
+    stats: StatsRecorder,
+    // Derived events keep their sources alive, sources only hold weak handles to them
+    pub(crate) upstream: Mutex<Vec<Arc<dyn Any + Send + Sync>>>,
 }
 
-impl<T: Clone + Send> EventInner<T> {
+impl<T: Clone + Send, E: CallbackError> EventInner<T, E> {
     pub fn id(&self) -> u64 {
         self.id
     }
@@ -39,13 +97,153 @@ impl<T: Clone + Send> EventInner<T> {
         self.subscribers.len()
     }
 
+    pub fn subscriber_count_watch(&self) -> watch::Receiver<usize> {
+        self.subscriber_count_sender.subscribe()
+    }
+
+    pub fn subscribe_defaults(&self) -> SubscribeDefaults {
+        self.subscribe_defaults
+    }
+
+    pub fn dispatch_mode(&self) -> DispatchMode {
+        self.dispatch_mode
+    }
+
+    pub fn max_concurrency(&self) -> Option<usize> {
+        self.max_concurrency
+    }
+
+    pub fn is_ordered(&self) -> bool {
+        self.ordered
+    }
+
+    pub fn replay_capacity(&self) -> usize {
+        self.replay_capacity
+    }
+
+    pub fn metrics_enabled(&self) -> bool {
+        self.metrics
+    }
+
+    pub fn runtime(&self) -> &Arc<dyn Runtime> {
+        &self.runtime
+    }
+
+    pub fn stats(&self) -> EventStats {
+        self.stats.snapshot()
+    }
+
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    pub fn subscriber_stats(&self, id: u64) -> Option<SubscriberStats> {
+        self.stats.subscriber_snapshot(id)
+    }
+
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limiter
+            .read()
+            .as_ref()
+            .map(|rate_limiter| rate_limiter.limit())
+    }
+
+    pub fn set_rate_limit(&self, rate_limit: Option<RateLimit>) {
+        let rate_limiter = rate_limit.map(|limit| Arc::new(RateLimiter::new(limit)));
+        *self.rate_limiter.write() = rate_limiter;
+    }
+
+    pub fn has_error_handler(&self) -> bool {
+        self.error_handler.read().is_some()
+    }
+
+    pub fn set_error_handler(
+        &self,
+        handler: impl Fn(&DispatchError<T, E>, &SubscriberInfo) + Send + Sync + 'static,
+    ) {
+        *self.error_handler.write() = Some(Arc::new(handler));
+    }
+
+    pub fn remove_error_handler(&self) -> bool {
+        self.error_handler.write().take().is_some()
+    }
+
+    // Replaces the built-in warning log for slow consumers
+    pub fn set_slow_consumer_handler(
+        &self,
+        handler: impl Fn(&SlowConsumerWarning) + Send + Sync + 'static,
+    ) {
+        *self.slow_consumer_handler.write() = Some(Arc::new(handler));
+    }
+
+    pub fn remove_slow_consumer_handler(&self) -> bool {
+        self.slow_consumer_handler.write().take().is_some()
+    }
+
+    pub fn has_dead_letter_sink(&self) -> bool {
+        self.dead_letter_forwarder.read().is_some()
+    }
+
+    pub fn remove_dead_letter_sink(&self) -> bool {
+        self.dead_letter_forwarder.write().take().is_some()
+    }
+
+    pub fn max_subscribers(&self) -> Option<usize> {
+        *self.max_subscribers.lock()
+    }
+
+    // Lowering the limit below the current subscriber count only rejects new subscribers
+    pub fn set_max_subscribers(&self, max_subscribers: Option<usize>) {
+        *self.max_subscribers.lock() = max_subscribers;
+    }
+}
+
+impl<T: Clone + Send + 'static, E: CallbackError> EventInner<T, E> {
+    pub fn subscribe(&self, subscriber: Subscriber<T, E>) -> Result<u64, SubscribeError> {
+        // The limit stays locked until the insert so concurrent subscribers can't overshoot it
+        let max_subscribers = self.max_subscribers.lock();
+        if let Some(max_subscribers) = *max_subscribers
+            && self.subscribers.len() >= max_subscribers
+        {
+            return Err(SubscribeError::MaxSubscribersReached {
+                event_name: self.name.clone(),
+                max_subscribers,
+            });
+        }
+
+        let id = subscriber.id();
+        let subscriber = Arc::new(subscriber);
+
+        // Dispatches record their payload and pick their subscribers under the replay lock, so
+        // every payload is either in the snapshot or reaches the subscriber, never both. The
+        // snapshot is delivered after the locks are released.
+        let replay = (self.replay_capacity > 0).then(|| self.replay.lock());
+        let replaying = replay.as_ref().is_some_and(|replay| !replay.is_empty());
+        if let Some(replay) = &replay
+            && replaying
+        {
+            subscriber.start_replay(VecDeque::clone(replay));
+        }
+
+        self.subscribers.insert(id, subscriber.clone());
+        drop(replay);
+        drop(max_subscribers);
+        self.publish_subscriber_count();
+
+        if replaying {
+            subscriber.finish_replay(&*self.runtime);
+        }
+
+        Ok(id)
+    }
+
     pub fn subscribe_channel(
         &self,
         name: impl Into<String>,
         buffer: usize,
         log_on_error: bool,
         remove_on_error: bool,
-    ) -> (u64, Receiver<T>) {
+    ) -> Result<(u64, Receiver<T>), SubscribeError> {
         let (sender, receiver) = channel(buffer);
 
         let subscriber = Subscriber::new(
@@ -55,19 +253,18 @@ impl<T: Clone + Send> EventInner<T> {
             Callback::Channel(sender),
         );
 
-        let id = subscriber.id();
-        self.subscribers.insert(id, subscriber);
+        let id = self.subscribe(subscriber)?;
 
-        (id, receiver)
+        Ok((id, receiver))
     }
 
     pub fn subscribe_async_closure(
         &self,
         name: impl Into<String>,
-        closure: impl Fn(T) -> PinnedBoxedFutureResult<()> + Send + Sync + 'static,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
         log_on_error: bool,
         remove_on_error: bool,
-    ) -> u64 {
+    ) -> Result<u64, SubscribeError> {
         let subscriber = Subscriber::new(
             name,
             log_on_error,
@@ -75,19 +272,50 @@ impl<T: Clone + Send> EventInner<T> {
             Callback::AsyncClosure(Box::new(closure)),
         );
 
-        let id = subscriber.id();
-        self.subscribers.insert(id, subscriber);
+        self.subscribe(subscriber)
+    }
 
-        id
+    pub fn subscribe_flow_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<HandlerFlow, E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::FlowClosure(Box::new(closure)),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn subscribe_async_flow_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<HandlerFlow, E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::AsyncFlowClosure(Box::new(closure)),
+        );
+
+        self.subscribe(subscriber)
     }
 
     pub fn subscribe_closure(
         &self,
         name: impl Into<String>,
-        closure: impl Fn(T) -> Result<(), BoxedError> + Send + Sync + 'static,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
         log_on_error: bool,
         remove_on_error: bool,
-    ) -> u64 {
+    ) -> Result<u64, SubscribeError> {
         let subscriber = Subscriber::new(
             name,
             log_on_error,
@@ -95,176 +323,1165 @@ impl<T: Clone + Send> EventInner<T> {
             Callback::Closure(Box::new(closure)),
         );
 
-        let id = subscriber.id();
-        self.subscribers.insert(id, subscriber);
+        self.subscribe(subscriber)
+    }
 
-        id
+    pub fn subscribe_channel_with_defaults(
+        &self,
+        name: impl Into<String>,
+    ) -> Result<(u64, Receiver<T>), SubscribeError> {
+        let defaults = self.subscribe_defaults;
+        self.subscribe_channel(
+            name,
+            defaults.channel_buffer,
+            defaults.log_on_error,
+            defaults.remove_on_error,
+        )
     }
 
-    pub fn unsubscribe(&self, id: u64) -> bool {
-        let value = self.subscribers.remove(&id);
-        value.is_some()
+    pub fn subscribe_async_closure_with_defaults(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+    ) -> Result<u64, SubscribeError> {
+        let defaults = self.subscribe_defaults;
+        self.subscribe_async_closure(
+            name,
+            closure,
+            defaults.log_on_error,
+            defaults.remove_on_error,
+        )
     }
 
-    //TODO: Docs about cancelation safety. data can be dropped without reaching a channel.
-    pub async fn dispatch(&self, data: T) -> Result<(), Vec<DispatchError<T>>> {
-        let mut errors = Vec::new();
-        let mut subscribers_to_remove = Vec::new();
+    pub fn subscribe_closure_with_defaults(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+    ) -> Result<u64, SubscribeError> {
+        let defaults = self.subscribe_defaults;
+        self.subscribe_closure(
+            name,
+            closure,
+            defaults.log_on_error,
+            defaults.remove_on_error,
+        )
+    }
 
-        for ref_multi in self.subscribers.iter() {
-            let id = *ref_multi.key();
-            let subscriber = ref_multi.value();
+    // Keeps CPU bound or blocking IO work off the async dispatch path
+    pub fn subscribe_blocking_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError>
+    where
+        T: 'static,
+    {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::blocking(closure),
+        );
 
-            let data = data.clone();
-            let result = subscriber.dispatch(data).await;
-            if let Err(err) = result {
-                //TODO: Remove log_on_error/remove_on_error -> provide closure for error handling?
-                if subscriber.log_on_error() {
-                    error!(
-                        "Event \"{}\" failed to dispatch data to subscriber \"{}\": {}.",
-                        self.name,
-                        subscriber.name(),
-                        err
-                    );
-                }
+        self.subscribe(subscriber)
+    }
 
-                if subscriber.remove_on_error() {
-                    if subscriber.log_on_error() {
-                        error!(
-                            "Event \"{}\" will remove subscriber \"{}\" due to the error.",
-                            self.name,
-                            subscriber.name()
-                        );
-                    }
+    // Payloads are collected in a task on the event's runtime. Batch errors can only be logged.
+    pub fn subscribe_batched(
+        &self,
+        name: impl Into<String>,
+        batch: Batch,
+        closure: impl Fn(Vec<T>) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError>
+    where
+        T: 'static,
+    {
+        let name = name.into();
+        let (sender, receiver) = channel(batch.max_items.max(1));
+        let subscriber = Subscriber::new(
+            name.clone(),
+            log_on_error,
+            remove_on_error,
+            Callback::Channel(sender),
+        );
+        let id = self.subscribe(subscriber)?;
 
-                    subscribers_to_remove.push(id);
-                }
+        let batch_loop = BatchLoop {
+            name,
+            batch,
+            closure: Box::new(closure),
+            log_on_error,
+            remove_on_error,
+        };
+        self.runtime
+            .spawn(Box::pin(batch_loop.run(self.runtime.clone(), receiver)));
 
-                errors.push(err);
-            }
-        }
+        Ok(id)
+    }
 
-        for id in subscribers_to_remove.into_iter() {
-            self.subscribers.remove(&id);
-        }
+    pub fn subscribe_handler<H: EventHandler<T, E> + 'static>(
+        &self,
+        name: impl Into<String>,
+        handler: Arc<H>,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Handler(handler),
+        );
 
-        if !errors.is_empty() {
-            return Err(errors);
-        }
+        self.subscribe(subscriber)
+    }
 
-        Ok(())
+    pub fn subscribe_custom(
+        &self,
+        name: impl Into<String>,
+        callback: impl CustomCallback<T, E> + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Custom(Box::new(callback)),
+        );
+
+        self.subscribe(subscriber)
     }
-}
 
-impl<T: Clone + Send> PartialEq for EventInner<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+    #[cfg(feature = "serde")]
+    pub fn subscribe_writer<W: AsyncWrite + Unpin + Send + 'static>(
+        &self,
+        name: impl Into<String>,
+        writer: W,
+        framing: Framing,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError>
+    where
+        T: Serialize,
+        E: From<WriteError>,
+    {
+        let writer = SerializeWriter::new(writer, framing);
+        self.subscribe_custom(name, writer, log_on_error, remove_on_error)
     }
-}
-impl<T: Clone + Send> Eq for EventInner<T> {}
 
-impl<T: Clone + Send> Hash for EventInner<T> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.id.hash(state);
+    // Lets an existing crossbeam based worker pool receive the data directly
+    #[cfg(feature = "crossbeam")]
+    pub fn subscribe_crossbeam(
+        &self,
+        name: impl Into<String>,
+        sender: crossbeam_channel::Sender<T>,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Crossbeam(sender),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    #[cfg(feature = "futures")]
+    pub fn subscribe_sink<SE: Into<E> + 'static>(
+        &self,
+        name: impl Into<String>,
+        sink: impl Sink<T, Error = SE> + Send + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(name, log_on_error, remove_on_error, Callback::sink(sink));
+        self.subscribe(subscriber)
+    }
+
+    // Spawned closures can't fail a dispatch, so there is no remove_on_error
+    pub fn subscribe_spawned_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            false,
+            Callback::Spawned(Box::new(closure)),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn subscribe_closure_mut(
+        &self,
+        name: impl Into<String>,
+        closure: impl FnMut(T) -> Result<(), E> + Send + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::ClosureMut(Mutex::new(Box::new(closure))),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn subscribe_async_closure_owned<O: Send + Sync + 'static>(
+        &self,
+        name: impl Into<String>,
+        owner: &Arc<O>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::AsyncClosure(Box::new(closure)),
+        )
+        .with_owner(owner);
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn subscribe_closure_owned<O: Send + Sync + 'static>(
+        &self,
+        name: impl Into<String>,
+        owner: &Arc<O>,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Closure(Box::new(closure)),
+        )
+        .with_owner(owner);
+
+        self.subscribe(subscriber)
+    }
+
+    // The closure only holds a weak reference to the owner and gets it upgraded for every payload
+    pub fn subscribe_closure_weak<O: Send + Sync + 'static>(
+        &self,
+        name: impl Into<String>,
+        owner: &Arc<O>,
+        closure: impl Fn(Arc<O>, T) -> Result<(), E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let weak_owner = Arc::downgrade(owner);
+        let closure = move |data| match weak_owner.upgrade() {
+            Some(owner) => closure(owner, data),
+            None => Ok(()), // The subscriber is pruned with the owner
+        };
+
+        self.subscribe_closure_owned(name, owner, closure, log_on_error, remove_on_error)
     }
 }
 
-impl<T: Clone + Send> Debug for EventInner<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let sub_count = self.subscribers.len();
+impl<T: Clone + Send, E: CallbackError> EventInner<T, E> {
+    pub fn unsubscribe(&self, id: u64) -> bool {
+        self.remove_subscriber(id)
+    }
+
+    // Only affects the delivery order of ordered events
+    pub fn set_subscriber_priority(&self, id: u64, priority: i32) -> bool {
+        match self.subscribers.get(&id) {
+            Some(ref_multi) => {
+                ref_multi.value().set_priority(priority);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Paused subscribers skip payloads until they are resumed
+    pub fn pause_subscriber(&self, id: u64) -> bool {
+        self.subscribers
+            .get(&id)
+            .is_some_and(|ref_multi| ref_multi.value().pause())
+    }
+
+    // Also resumes subscribers that paused themselves through HandlerFlow::PauseSelf
+    pub fn resume_subscriber(&self, id: u64) -> bool {
+        self.subscribers
+            .get(&id)
+            .is_some_and(|ref_multi| ref_multi.value().resume())
+    }
+
+    // Checks the subscriber without dispatching. Unknown ids are reported as not alive.
+    pub fn is_subscriber_alive(&self, id: u64) -> bool {
+        self.subscribers
+            .get(&id)
+            .is_some_and(|ref_multi| ref_multi.value().is_alive())
+    }
+
+    pub fn group_subscriber_count(&self, group: &str) -> usize {
+        self.subscribers
+            .iter()
+            .filter(|ref_multi| ref_multi.value().group() == Some(group))
+            .count()
+    }
+
+    pub fn unsubscribe_group(&self, group: &str) -> usize {
+        let ids: Vec<u64> = self
+            .subscribers
+            .iter()
+            .filter(|ref_multi| ref_multi.value().group() == Some(group))
+            .map(|ref_multi| *ref_multi.key())
+            .collect();
+
+        ids.into_iter().filter(|id| self.unsubscribe(*id)).count()
+    }
+
+    // Waits until every channel subscriber drained the payloads dispatched before the call
+    pub async fn flush(&self) {
+        let subscribers = self.snapshot_subscribers();
+        for subscriber in subscribers.iter() {
+            subscriber.flush().await;
+        }
+    }
+
+    //TODO: Docs about cancelation safety. data can be dropped without reaching a channel.
+    pub async fn dispatch(&self, data: T) -> Result<(), Vec<DispatchFailure<T, E>>> {
+        self.dispatch_report(data).await.into_result()
+    }
+
+    pub async fn dispatch_with_cancel(
+        &self,
+        data: T,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<(), CancellableDispatchError<T, E>> {
+        let report = self.dispatch_report_with_cancel(data, cancel).await;
+        report.into_cancellable_result()
+    }
+
+    pub async fn dispatch_report(&self, data: T) -> DispatchReport<T, E> {
+        self.dispatch_report_with_cancel(data, pending()).await
+    }
+
+    pub async fn dispatch_report_with_cancel(
+        &self,
+        data: T,
+        cancel: impl Future<Output = ()>,
+    ) -> DispatchReport<T, E> {
+        traced!(
+            self.run_dispatch(data, cancel),
+            "dispatch",
+            event.id = self.id,
+            event.name = %self.name,
+            payload = type_name::<T>(),
+        )
+        .await
+    }
+
+    async fn run_dispatch(
+        &self,
+        data: T,
+        cancel: impl Future<Output = ()>,
+    ) -> DispatchReport<T, E> {
+        let mut cancel = pin!(cancel);
+
+        let rate_limit_result = select! {
+            biased;
+            _ = &mut cancel => None,
+            result = self.acquire_rate_limit(data) => Some(result),
+        };
+
+        let data = match rate_limit_result {
+            Some(Ok(data)) => data,
+            Some(Err(data)) => return DispatchReport::new_rate_limited(data),
+            None => {
+                let outcomes = self
+                    .snapshot_subscribers()
+                    .iter()
+                    .map(|subscriber| SubscriberOutcome {
+                        subscriber: subscriber.info(),
+                        outcome: DispatchOutcome::Skipped,
+                    })
+                    .collect();
+
+                return DispatchReport::new(outcomes);
+            }
+        };
+
+        let start = Instant::now();
+        let subscribers = {
+            let _replay = self.record_replay(&data);
+            self.snapshot_subscribers()
+        };
+        let payloads = payloads_for(data, subscribers.len());
+
+        // Subscribers without an outcome were not reached before the cancellation
+        let outcomes = match self.dispatch_mode {
+            DispatchMode::Sequential => {
+                self.deliver_sequentially(&subscribers, payloads, cancel)
+                    .await
+            }
+            DispatchMode::Concurrent => {
+                self.deliver_concurrently(&subscribers, payloads, cancel)
+                    .await
+            }
+        };
+
+        let outcomes = subscribers
+            .iter()
+            .zip(outcomes)
+            .map(|(subscriber, outcome)| {
+                let outcome = outcome.unwrap_or(DispatchOutcome::Skipped);
+                if matches!(outcome, DispatchOutcome::Removed(_)) || subscriber.is_stale() {
+                    self.remove_subscriber(subscriber.id());
+                }
+
+                SubscriberOutcome {
+                    subscriber: subscriber.info(),
+                    outcome,
+                }
+            })
+            .collect();
+
+        let report = DispatchReport::new(outcomes);
+        if self.metrics {
+            self.stats
+                .record_dispatch(start.elapsed(), report.error_count());
+        }
+
+        report
+    }
+
+    async fn deliver_sequentially(
+        &self,
+        subscribers: &[Arc<Subscriber<T, E>>],
+        payloads: impl Iterator<Item = T>,
+        mut cancel: Pin<&mut impl Future<Output = ()>>,
+    ) -> Vec<Option<DispatchOutcome<T, E>>> {
+        let mut outcomes: Vec<_> = subscribers.iter().map(|_| None).collect();
+
+        for ((subscriber, data), slot) in subscribers.iter().zip(payloads).zip(outcomes.iter_mut())
+        {
+            let outcome = select! {
+                biased;
+                _ = &mut cancel => None,
+                outcome = self.dispatch_to_subscriber(subscriber, data) => Some(outcome),
+            };
+
+            // The subscriber that was interrupted mid-delivery counts as skipped as well
+            let Some(outcome) = outcome else {
+                break;
+            };
+
+            *slot = Some(outcome);
+        }
+
+        outcomes
+    }
+
+    async fn deliver_concurrently(
+        &self,
+        subscribers: &[Arc<Subscriber<T, E>>],
+        payloads: impl Iterator<Item = T>,
+        mut cancel: Pin<&mut impl Future<Output = ()>>,
+    ) -> Vec<Option<DispatchOutcome<T, E>>> {
+        let mut deliveries: Vec<_> = subscribers
+            .iter()
+            .zip(payloads)
+            .map(|(subscriber, data)| Some(Box::pin(self.dispatch_to_subscriber(subscriber, data))))
+            .collect();
+        let mut outcomes: Vec<_> = subscribers.iter().map(|_| None).collect();
+
+        poll_fn(|cx| {
+            if cancel.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+
+            let mut all_done = true;
+            for (delivery, slot) in deliveries.iter_mut().zip(outcomes.iter_mut()) {
+                let Some(future) = delivery else {
+                    continue;
+                };
+
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(outcome) => {
+                        *slot = Some(outcome);
+                        *delivery = None;
+                    }
+                    Poll::Pending => all_done = false,
+                }
+            }
+
+            if all_done {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await;
+
+        outcomes
+    }
+
+    // The returned guard has to be held until the dispatch picked its subscribers
+    fn record_replay(&self, data: &T) -> Option<MutexGuard<'_, VecDeque<T>>> {
+        if self.replay_capacity == 0 {
+            return None;
+        }
+
+        let mut replay = self.replay.lock();
+        if replay.len() == self.replay_capacity {
+            replay.pop_front();
+        }
+        replay.push_back(data.clone());
+
+        Some(replay)
+    }
+
+    // Returns the data back if the dispatch has to be dropped
+    async fn acquire_rate_limit(&self, data: T) -> Result<T, T> {
+        let rate_limiter = self.rate_limiter.read().clone();
+        if let Some(rate_limiter) = rate_limiter
+            && !rate_limiter.acquire(&*self.runtime).await
+        {
+            return Err(data);
+        }
+
+        Ok(data)
+    }
+
+    // Snapshot the subscribers so no map locks are held across await points.
+    // Subscribers whose owner has been dropped, that used up their calls or unsubscribed themselves
+    // are pruned on the way.
+    fn snapshot_subscribers(&self) -> Vec<Arc<Subscriber<T, E>>> {
+        let mut subscribers = Vec::with_capacity(self.subscribers.len());
+        let mut stale = Vec::new();
+
+        for ref_multi in self.subscribers.iter() {
+            let subscriber = ref_multi.value();
+            if !subscriber.is_stale() {
+                subscribers.push(subscriber.clone());
+            } else {
+                stale.push(subscriber.id());
+            }
+        }
+
+        for id in stale.into_iter() {
+            self.remove_subscriber(id);
+        }
+
+        // Higher priorities go first. Ids are handed out in increasing order, so sorting by id
+        // restores subscription order among equal priorities.
+        if self.ordered {
+            subscribers.sort_unstable_by_key(|subscriber| {
+                (Reverse(subscriber.priority()), subscriber.id())
+            });
+        }
+
+        subscribers
+    }
+
+    fn remove_subscriber(&self, id: u64) -> bool {
+        let value = self.subscribers.remove(&id);
+        self.stats.forget_subscriber(id);
+
+        if value.is_some() {
+            self.publish_subscriber_count();
+        }
+
+        value.is_some()
+    }
+
+    // The count is read while the watch is locked, so concurrent changes can't publish a stale count
+    fn publish_subscriber_count(&self) {
+        self.subscriber_count_sender.send_if_modified(|count| {
+            let subscriber_count = self.subscribers.len();
+            let modified = *count != subscriber_count;
+            *count = subscriber_count;
+
+            modified
+        });
+    }
+
+    fn check_slow_consumer(
+        &self,
+        detection: &SlowConsumerDetection,
+        subscriber: &Subscriber<T, E>,
+        blocked_for: Duration,
+    ) {
+        let Some((buffered, capacity)) = subscriber.channel_usage() else {
+            return;
+        };
+
+        let congested = detection.is_congested(buffered, capacity, blocked_for);
+        let congested_sends = subscriber.record_send(congested);
+        if !detection.is_reported(congested_sends) {
+            return;
+        }
+
+        let warning = SlowConsumerWarning {
+            event_name: self.name.clone(),
+            subscriber: subscriber.info(),
+            buffered,
+            capacity,
+            blocked_for,
+            congested_sends,
+        };
+
+        let slow_consumer_handler = self.slow_consumer_handler.read().clone();
+        match slow_consumer_handler {
+            Some(handler) => handler(&warning),
+            None => warn!(
+                "Subscriber \"{}\" of event \"{}\" is a slow consumer: {} of {} buffered payloads, the last send blocked for {:?}.",
+                subscriber.name(),
+                self.name,
+                buffered,
+                capacity,
+                blocked_for
+            ),
+        }
+    }
+
+    async fn dispatch_to_subscriber(
+        &self,
+        subscriber: &Subscriber<T, E>,
+        data: T,
+    ) -> DispatchOutcome<T, E> {
+        // Delivered once the subscriber finished replaying, so it can't overtake the replayed data
+        let Some(data) = subscriber.defer_during_replay(data) else {
+            return DispatchOutcome::Delivered;
+        };
+
+        if !subscriber.accepts(&data) || !subscriber.try_claim_call() {
+            return DispatchOutcome::Filtered;
+        }
+
+        // Payloads are only kept around for a failed delivery if there is somewhere to send them
+        let dead_letter_forwarder = self.dead_letter_forwarder.read().clone();
+        let dead_letter_data = dead_letter_forwarder.as_ref().map(|_| data.clone());
+
+        // The permit only covers the callback itself, error handling runs outside of the limit
+        let permit = match &self.concurrency_limit {
+            Some(semaphore) => semaphore.acquire().await.ok(),
+            None => None,
+        };
+
+        let delivery = traced!(
+            subscriber.dispatch_on(data, &*self.runtime),
+            "deliver",
+            event.name = %self.name,
+            subscriber.id = subscriber.id(),
+            subscriber.name = subscriber.name(),
+            subscriber.labels = ?subscriber.labels(),
+        );
+
+        let delivery_start = Instant::now();
+        let result = match subscriber.timeout() {
+            Some(timeout) => {
+                let deadline = Instant::now() + timeout;
+                timeout_at(&*self.runtime, deadline, delivery)
+                    .await
+                    .unwrap_or(Err(DispatchError::Timeout(timeout)))
+            }
+            None => delivery.await,
+        };
+        drop(permit);
+
+        if let Some(detection) = &self.slow_consumer_detection {
+            self.check_slow_consumer(detection, subscriber, delivery_start.elapsed());
+        }
+
+        if self.metrics {
+            let error = result.as_ref().err().map(ToString::to_string);
+            self.stats
+                .record_subscriber_call(subscriber.id(), subscriber.labels(), error);
+        }
+
+        let err = match result {
+            Ok(()) => return DispatchOutcome::Delivered,
+            Err(err) => err,
+        };
+
+        // An installed error handler replaces the built-in logging
+        let error_handler = self.error_handler.read().clone();
+        match error_handler {
+            Some(error_handler) => error_handler(&err, &subscriber.info()),
+            None => {
+                if subscriber.log_on_error() {
+                    error!(
+                        "Event \"{}\" failed to dispatch data to subscriber \"{}\": {}.",
+                        self.name,
+                        subscriber.name(),
+                        err
+                    );
+                }
+
+                if subscriber.log_on_error() && subscriber.remove_on_error() {
+                    error!(
+                        "Event \"{}\" will remove subscriber \"{}\" due to the error.",
+                        self.name,
+                        subscriber.name()
+                    );
+                }
+            }
+        }
+
+        subscriber.notify_error(&err);
+
+        if let (Some(forwarder), Some(data)) = (dead_letter_forwarder, dead_letter_data) {
+            let dead_letter = DeadLetter {
+                event_id: self.id,
+                event_name: self.name.clone(),
+                subscriber: subscriber.info(),
+                error: err.to_string(),
+                data,
+            };
+
+            forwarder(dead_letter).await;
+        }
+
+        if subscriber.remove_on_error() {
+            return DispatchOutcome::Removed(err);
+        }
+
+        DispatchOutcome::Failed(err)
+    }
+}
+
+impl<T: Clone + Send + 'static, E: CallbackError> EventInner<T, E> {
+    pub fn set_dead_letter_sink(&self, sink: impl Into<DeadLetterSink<T>>) {
+        let forwarder = sink.into().into_forwarder();
+        *self.dead_letter_forwarder.write() = Some(forwarder);
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> PartialEq for EventInner<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+impl<T: Clone + Send, E: CallbackError> Eq for EventInner<T, E> {}
+
+impl<T: Clone + Send, E: CallbackError> Hash for EventInner<T, E> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Debug for EventInner<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let sub_count = self.subscribers.len();
+
+        f.debug_struct(type_name::<Self>())
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("subscribers", &sub_count)
+            .finish()
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Display for EventInner<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let sub_count = self.subscribers.len();
+        let sub_word = if sub_count == 1 {
+            "subscriber"
+        } else {
+            "subscribers"
+        };
+
+        write!(f, "Event {} ({} {})", self.name, sub_count, sub_word)
+    }
+}
+
+// The last payload is the original data, so only count - 1 clones are made
+fn payloads_for<T: Clone>(data: T, count: usize) -> impl Iterator<Item = T> {
+    let mut data = Some(data);
+    (0..count).filter_map(move |index| {
+        if index + 1 < count {
+            data.clone()
+        } else {
+            data.take()
+        }
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum CancellableDispatchError<T, E = BoxedError> {
+    #[error("Failed to dispatch data to {} subscribers", .0.len())]
+    Dispatch(Vec<DispatchFailure<T, E>>),
+
+    #[error("The dispatch was cancelled before reaching {} subscribers", skipped.len())]
+    Cancelled {
+        skipped: Vec<SubscriberInfo>,
+        errors: Vec<DispatchFailure<T, E>>,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum SubscribeError {
+    #[error("Event {event_name} reached its limit of {max_subscribers} subscribers")]
+    MaxSubscribersReached {
+        event_name: String,
+        max_subscribers: usize,
+    },
+
+    #[cfg(feature = "sync")]
+    #[error("SyncEvent {event_name} can't run subscriber {subscriber_name} without a runtime")]
+    NeedsRuntime {
+        event_name: String,
+        subscriber_name: String,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum EventHandleError {
+    #[error("The event has been dropped.")]
+    EventDropped,
+
+    #[error("The event rejected the subscriber: {0}")]
+    Subscribe(#[from] SubscribeError),
+}
+
+pub struct EventHandle<T: Clone + Send, E: CallbackError = BoxedError> {
+    inner: Weak<EventInner<T, E>>,
+}
+
+impl<T: Clone + Send, E: CallbackError> Clone for EventHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> EventHandle<T, E> {
+    pub fn id(&self) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.id();
+
+        Ok(id)
+    }
+
+    pub fn name(&self) -> Result<String, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let name = inner.name().to_string();
+
+        Ok(name)
+    }
+
+    pub fn subscriber_count(&self) -> Result<usize, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let count = inner.subscriber_count();
+
+        Ok(count)
+    }
+
+    pub fn set_subscriber_priority(
+        &self,
+        id: u64,
+        priority: i32,
+    ) -> Result<bool, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let result = inner.set_subscriber_priority(id, priority);
+
+        Ok(result)
+    }
+
+    pub fn pause_subscriber(&self, id: u64) -> Result<bool, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let paused = inner.pause_subscriber(id);
+
+        Ok(paused)
+    }
+
+    pub fn resume_subscriber(&self, id: u64) -> Result<bool, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let resumed = inner.resume_subscriber(id);
+
+        Ok(resumed)
+    }
+
+    pub fn is_subscriber_alive(&self, id: u64) -> Result<bool, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let alive = inner.is_subscriber_alive(id);
+
+        Ok(alive)
+    }
+
+    pub fn stats(&self) -> Result<EventStats, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let stats = inner.stats();
+
+        Ok(stats)
+    }
+
+    pub fn subscriber_stats(&self, id: u64) -> Result<Option<SubscriberStats>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let stats = inner.subscriber_stats(id);
+
+        Ok(stats)
+    }
+}
+
+impl<T: Clone + Send + 'static, E: CallbackError> EventHandle<T, E> {
+    pub fn subscribe(&self, subscriber: Subscriber<T, E>) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe(subscriber)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<(u64, Receiver<T>), EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let result = inner.subscribe_channel(name, buffer, log_on_error, remove_on_error)?;
+
+        Ok(result)
+    }
+
+    pub fn subscribe_async_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_async_closure(name, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_flow_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<HandlerFlow, E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_flow_closure(name, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_async_flow_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<HandlerFlow, E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id =
+            inner.subscribe_async_flow_closure(name, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_closure(name, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_blocking_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError>
+    where
+        T: 'static,
+    {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_blocking_closure(name, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_batched(
+        &self,
+        name: impl Into<String>,
+        batch: Batch,
+        closure: impl Fn(Vec<T>) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError>
+    where
+        T: 'static,
+    {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_batched(name, batch, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_handler<H: EventHandler<T, E> + 'static>(
+        &self,
+        name: impl Into<String>,
+        handler: Arc<H>,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_handler(name, handler, log_on_error, remove_on_error)?;
 
-        f.debug_struct(type_name::<Self>())
-            .field("id", &self.id)
-            .field("name", &self.name)
-            .field("subscribers", &sub_count)
-            .finish()
+        Ok(id)
     }
-}
 
-impl<T: Clone + Send> Display for EventInner<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let sub_count = self.subscribers.len();
-        let sub_word = if sub_count == 1 {
-            "subscriber"
-        } else {
-            "subscribers"
-        };
+    pub fn subscribe_custom(
+        &self,
+        name: impl Into<String>,
+        callback: impl CustomCallback<T, E> + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_custom(name, callback, log_on_error, remove_on_error)?;
 
-        write!(f, "Event {} ({} {})", self.name, sub_count, sub_word)
+        Ok(id)
     }
-}
 
-#[derive(Debug, Error)]
-pub enum EventHandleError {
-    #[error("The event has been dropped.")]
-    EventDropped,
-}
+    #[cfg(feature = "serde")]
+    pub fn subscribe_writer<W: AsyncWrite + Unpin + Send + 'static>(
+        &self,
+        name: impl Into<String>,
+        writer: W,
+        framing: Framing,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError>
+    where
+        T: Serialize,
+        E: From<WriteError>,
+    {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_writer(name, writer, framing, log_on_error, remove_on_error)?;
 
-#[derive(Clone)]
-pub struct EventHandle<T: Clone + Send> {
-    inner: Weak<EventInner<T>>,
-}
+        Ok(id)
+    }
 
-impl<T: Clone + Send> EventHandle<T> {
-    pub fn id(&self) -> Result<u64, EventHandleError> {
+    #[cfg(feature = "crossbeam")]
+    pub fn subscribe_crossbeam(
+        &self,
+        name: impl Into<String>,
+        sender: crossbeam_channel::Sender<T>,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
-        let id = inner.id();
+        let id = inner.subscribe_crossbeam(name, sender, log_on_error, remove_on_error)?;
 
         Ok(id)
     }
 
-    pub fn name(&self) -> Result<String, EventHandleError> {
+    #[cfg(feature = "futures")]
+    pub fn subscribe_sink<SE: Into<E> + 'static>(
+        &self,
+        name: impl Into<String>,
+        sink: impl Sink<T, Error = SE> + Send + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
-        let name = inner.name().to_string();
+        let id = inner.subscribe_sink(name, sink, log_on_error, remove_on_error)?;
 
-        Ok(name)
+        Ok(id)
     }
 
-    pub fn subscriber_count(&self) -> Result<usize, EventHandleError> {
+    pub fn subscribe_spawned_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
-        let count = inner.subscriber_count();
+        let id = inner.subscribe_spawned_closure(name, closure, log_on_error)?;
 
-        Ok(count)
+        Ok(id)
     }
 
-    pub fn subscribe_channel(
+    pub fn subscribe_closure_mut(
         &self,
         name: impl Into<String>,
-        buffer: usize,
+        closure: impl FnMut(T) -> Result<(), E> + Send + 'static,
         log_on_error: bool,
         remove_on_error: bool,
+    ) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.subscribe_closure_mut(name, closure, log_on_error, remove_on_error)?;
+
+        Ok(id)
+    }
+
+    pub fn subscribe_channel_with_defaults(
+        &self,
+        name: impl Into<String>,
     ) -> Result<(u64, Receiver<T>), EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
-        let result = inner.subscribe_channel(name, buffer, log_on_error, remove_on_error);
+        let result = inner.subscribe_channel_with_defaults(name)?;
 
         Ok(result)
     }
 
-    pub fn subscribe_async_closure(
+    pub fn subscribe_async_closure_with_defaults(
         &self,
         name: impl Into<String>,
-        closure: impl Fn(T) -> PinnedBoxedFutureResult<()> + Send + Sync + 'static,
-        log_on_error: bool,
-        remove_on_error: bool,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
     ) -> Result<u64, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
-        let id = inner.subscribe_async_closure(name, closure, log_on_error, remove_on_error);
+        let id = inner.subscribe_async_closure_with_defaults(name, closure)?;
 
         Ok(id)
     }
 
-    pub fn subscribe_closure(
+    pub fn subscribe_closure_with_defaults(
         &self,
         name: impl Into<String>,
-        closure: impl Fn(T) -> Result<(), BoxedError> + Send + Sync + 'static,
-        log_on_error: bool,
-        remove_on_error: bool,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
     ) -> Result<u64, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
-        let id = inner.subscribe_closure(name, closure, log_on_error, remove_on_error);
+        let id = inner.subscribe_closure_with_defaults(name, closure)?;
 
         Ok(id)
     }
+}
+
+impl<T: Clone + Send, E: CallbackError> EventHandle<T, E> {
+    pub fn subscriber_count_watch(&self) -> Result<watch::Receiver<usize>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let receiver = inner.subscriber_count_watch();
+
+        Ok(receiver)
+    }
 
     pub fn unsubscribe(&self, id: u64) -> Result<bool, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
@@ -273,23 +1490,55 @@ impl<T: Clone + Send> EventHandle<T> {
         Ok(result)
     }
 
+    pub fn unsubscribe_group(&self, group: &str) -> Result<usize, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let count = inner.unsubscribe_group(group);
+
+        Ok(count)
+    }
+
     pub async fn dispatch(
         &self,
         data: T,
-    ) -> Result<Result<(), Vec<DispatchError<T>>>, EventHandleError> {
+    ) -> Result<Result<(), Vec<DispatchFailure<T, E>>>, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
         let result = inner.dispatch(data).await;
 
         Ok(result)
     }
 
+    pub async fn dispatch_report(&self, data: T) -> Result<DispatchReport<T, E>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let report = inner.dispatch_report(data).await;
+
+        Ok(report)
+    }
+
+    pub async fn dispatch_with_cancel(
+        &self,
+        data: T,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Result<(), CancellableDispatchError<T, E>>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let result = inner.dispatch_with_cancel(data, cancel).await;
+
+        Ok(result)
+    }
+
+    pub async fn flush(&self) -> Result<(), EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        inner.flush().await;
+
+        Ok(())
+    }
+
     pub fn is_dropped(&self) -> bool {
         self.inner.strong_count() == 0
     }
 
     pub fn try_with<R>(
         &self,
-        func: impl FnOnce(&EventInner<T>) -> R,
+        func: impl FnOnce(&EventInner<T, E>) -> R,
     ) -> Result<R, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
         let result = func(&inner);
@@ -299,7 +1548,7 @@ impl<T: Clone + Send> EventHandle<T> {
 
     pub async fn try_with_async<R>(
         &self,
-        func: impl AsyncFnOnce(&EventInner<T>) -> R,
+        func: impl AsyncFnOnce(&EventInner<T, E>) -> R,
     ) -> Result<R, EventHandleError> {
         let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
         let result = func(&inner).await;
@@ -308,38 +1557,38 @@ impl<T: Clone + Send> EventHandle<T> {
     }
 }
 
-impl<T: Clone + Send> From<Event<T>> for EventHandle<T> {
-    fn from(event: Event<T>) -> Self {
+impl<T: Clone + Send, E: CallbackError> From<Event<T, E>> for EventHandle<T, E> {
+    fn from(event: Event<T, E>) -> Self {
         event.handle()
     }
 }
 
-impl<T: Clone + Send> From<&Event<T>> for EventHandle<T> {
-    fn from(event: &Event<T>) -> Self {
+impl<T: Clone + Send, E: CallbackError> From<&Event<T, E>> for EventHandle<T, E> {
+    fn from(event: &Event<T, E>) -> Self {
         event.handle()
     }
 }
 
-impl<T: Clone + Send> From<&mut Event<T>> for EventHandle<T> {
-    fn from(event: &mut Event<T>) -> Self {
+impl<T: Clone + Send, E: CallbackError> From<&mut Event<T, E>> for EventHandle<T, E> {
+    fn from(event: &mut Event<T, E>) -> Self {
         event.handle()
     }
 }
 
-impl<T: Clone + Send> AsRef<EventHandle<T>> for EventHandle<T> {
-    fn as_ref(&self) -> &EventHandle<T> {
+impl<T: Clone + Send, E: CallbackError> AsRef<EventHandle<T, E>> for EventHandle<T, E> {
+    fn as_ref(&self) -> &EventHandle<T, E> {
         self
     }
 }
 
-impl<T: Clone + Send> PartialEq for EventHandle<T> {
+impl<T: Clone + Send, E: CallbackError> PartialEq for EventHandle<T, E> {
     fn eq(&self, other: &Self) -> bool {
         self.inner.ptr_eq(&other.inner)
     }
 }
 
-impl<T: Clone + Send> PartialEq<EventInner<T>> for EventHandle<T> {
-    fn eq(&self, other: &EventInner<T>) -> bool {
+impl<T: Clone + Send, E: CallbackError> PartialEq<EventInner<T, E>> for EventHandle<T, E> {
+    fn eq(&self, other: &EventInner<T, E>) -> bool {
         match self.inner.upgrade() {
             Some(inner) => *inner == *other,
             None => false,
@@ -347,10 +1596,10 @@ impl<T: Clone + Send> PartialEq<EventInner<T>> for EventHandle<T> {
     }
 }
 
-impl<T: Clone + Send> Debug for EventHandle<T> {
+impl<T: Clone + Send, E: CallbackError> Debug for EventHandle<T, E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self.inner.upgrade() {
-            Some(inner) => <EventInner<T> as Debug>::fmt(&inner, f),
+            Some(inner) => <EventInner<T, E> as Debug>::fmt(&inner, f),
             None => f
                 .debug_struct(type_name::<Self>())
                 .field("inner", &"dropped")
@@ -359,28 +1608,112 @@ impl<T: Clone + Send> Debug for EventHandle<T> {
     }
 }
 
-impl<T: Clone + Send> Display for EventHandle<T> {
+impl<T: Clone + Send, E: CallbackError> Display for EventHandle<T, E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self.inner.upgrade() {
-            Some(inner) => <EventInner<T> as Display>::fmt(&inner, f),
+            Some(inner) => <EventInner<T, E> as Display>::fmt(&inner, f),
             None => write!(f, "EventHandle (dropped)"),
         }
     }
 }
 
-pub struct Event<T: Clone + Send> {
-    inner: Arc<EventInner<T>>,
+// Unlike EventHandle, a WeakEvent can be upgraded back into a full Event
+pub struct WeakEvent<T: Clone + Send, E: CallbackError = BoxedError> {
+    inner: Weak<EventInner<T, E>>,
+}
+
+impl<T: Clone + Send, E: CallbackError> Clone for WeakEvent<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> WeakEvent<T, E> {
+    pub fn upgrade(&self) -> Option<Event<T, E>> {
+        self.inner.upgrade().map(|inner| Event { inner })
+    }
+
+    pub fn is_dropped(&self) -> bool {
+        self.inner.strong_count() == 0
+    }
+
+    pub fn handle(&self) -> EventHandle<T, E> {
+        EventHandle {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> From<&Event<T, E>> for WeakEvent<T, E> {
+    fn from(event: &Event<T, E>) -> Self {
+        event.downgrade()
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> PartialEq for WeakEvent<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.ptr_eq(&other.inner)
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Debug for WeakEvent<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.inner.upgrade() {
+            Some(inner) => <EventInner<T, E> as Debug>::fmt(&inner, f),
+            None => f
+                .debug_struct(type_name::<Self>())
+                .field("inner", &"dropped")
+                .finish(),
+        }
+    }
+}
+
+pub struct Event<T: Clone + Send, E: CallbackError = BoxedError> {
+    pub(crate) inner: Arc<EventInner<T, E>>,
 }
 
+// Events with a custom callback error type are created through EventBuilder::<T, E>::new
 impl<T: Clone + Send> Event<T> {
     pub fn new(name: impl Into<String>) -> Self {
+        Self::builder(name).build()
+    }
+
+    pub fn builder(name: impl Into<String>) -> EventBuilder<T> {
+        EventBuilder::new(name)
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Event<T, E> {
+    pub(crate) fn from_builder(builder: EventBuilder<T, E>) -> Self {
         let id = get_unique_id();
-        let name = name.into();
+        let rate_limiter = builder
+            .rate_limit
+            .map(|limit| Arc::new(RateLimiter::new(limit)));
 
         let inner = EventInner {
             id,
-            name,
+            name: builder.name,
             subscribers: DashMap::new(),
+            rate_limiter: RwLock::new(rate_limiter),
+            error_handler: RwLock::new(None),
+            dead_letter_forwarder: RwLock::new(None),
+            slow_consumer_detection: builder.slow_consumer_detection,
+            slow_consumer_handler: RwLock::new(None),
+            max_subscribers: Mutex::new(builder.max_subscribers),
+            subscribe_defaults: builder.subscribe_defaults,
+            dispatch_mode: builder.dispatch_mode,
+            max_concurrency: builder.max_concurrency,
+            concurrency_limit: builder.max_concurrency.map(Semaphore::new),
+            ordered: builder.ordered,
+            replay_capacity: builder.replay,
+            replay: Mutex::new(VecDeque::new()),
+            metrics: builder.metrics,
+            subscriber_count_sender: watch::Sender::new(0),
+            runtime: builder.runtime,
+            stats: StatsRecorder::default(),
+            upstream: Mutex::new(Vec::new()),
         };
 
         Self {
@@ -388,28 +1721,78 @@ impl<T: Clone + Send> Event<T> {
         }
     }
 
-    pub fn handle(&self) -> EventHandle<T> {
+    pub fn handle(&self) -> EventHandle<T, E> {
         let weak = Arc::downgrade(&self.inner);
         EventHandle { inner: weak }
     }
+
+    pub fn downgrade(&self) -> WeakEvent<T, E> {
+        let weak = Arc::downgrade(&self.inner);
+        WeakEvent { inner: weak }
+    }
+}
+
+impl<T: Clone + Send + 'static, E: CallbackError> Event<T, E> {
+    pub fn subscribe_channel_guarded(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<(SubscriptionGuard<T, E>, Receiver<T>), SubscribeError> {
+        let (id, receiver) = self.subscribe_channel(name, buffer, log_on_error, remove_on_error)?;
+        let guard = SubscriptionGuard::new(self, id);
+
+        Ok((guard, receiver))
+    }
+
+    pub fn subscribe_async_closure_guarded(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<SubscriptionGuard<T, E>, SubscribeError> {
+        let id = self.subscribe_async_closure(name, closure, log_on_error, remove_on_error)?;
+        Ok(SubscriptionGuard::new(self, id))
+    }
+
+    pub fn subscribe_closure_guarded(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<SubscriptionGuard<T, E>, SubscribeError> {
+        let id = self.subscribe_closure(name, closure, log_on_error, remove_on_error)?;
+        Ok(SubscriptionGuard::new(self, id))
+    }
+
+    pub fn dispatch_after(&self, data: T, delay: Duration) -> ScheduledDispatch {
+        self.dispatch_at(data, Instant::now() + delay)
+    }
+
+    pub fn dispatch_at(&self, data: T, at: impl Into<Instant>) -> ScheduledDispatch {
+        ScheduledDispatch::spawn(self.runtime.clone(), self.handle(), data, at.into())
+    }
 }
 
-impl<T: Clone + Send> Deref for Event<T> {
-    type Target = EventInner<T>;
+impl<T: Clone + Send, E: CallbackError> Deref for Event<T, E> {
+    type Target = EventInner<T, E>;
 
     fn deref(&self) -> &Self::Target {
         &self.inner
     }
 }
 
-impl<T: Clone + Send> PartialEq for Event<T> {
+impl<T: Clone + Send, E: CallbackError> PartialEq for Event<T, E> {
     fn eq(&self, other: &Self) -> bool {
         self.inner == other.inner
     }
 }
 
-impl<T: Clone + Send> PartialEq<EventHandle<T>> for Event<T> {
-    fn eq(&self, other: &EventHandle<T>) -> bool {
+impl<T: Clone + Send, E: CallbackError> PartialEq<EventHandle<T, E>> for Event<T, E> {
+    fn eq(&self, other: &EventHandle<T, E>) -> bool {
         match other.inner.upgrade() {
             Some(other_inner) => self.inner == other_inner,
             None => false,
@@ -417,20 +1800,630 @@ impl<T: Clone + Send> PartialEq<EventHandle<T>> for Event<T> {
     }
 }
 
-impl<T: Clone + Send> Hash for Event<T> {
+impl<T: Clone + Send, E: CallbackError> Hash for Event<T, E> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.inner.hash(state);
     }
 }
 
-impl<T: Clone + Send> Debug for Event<T> {
+impl<T: Clone + Send, E: CallbackError> Debug for Event<T, E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        <EventInner<T> as Debug>::fmt(&self.inner, f)
+        <EventInner<T, E> as Debug>::fmt(&self.inner, f)
     }
 }
 
-impl<T: Clone + Send> Display for Event<T> {
+impl<T: Clone + Send, E: CallbackError> Display for Event<T, E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        <EventInner<T> as Display>::fmt(&self.inner, f)
+        <EventInner<T, E> as Display>::fmt(&self.inner, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use lum_libs::tokio::{self, time::sleep};
+
+    const EVENT_NAME: &str = "test_event";
+    const CLOSURE_NAME: &str = "test_closure";
+
+    #[derive(Debug)]
+    struct CloneCounter(Arc<AtomicUsize>);
+
+    impl Clone for CloneCounter {
+        fn clone(&self) -> Self {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Self(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_clones_once_per_additional_subscriber() {
+        let event = Event::<CloneCounter>::new(EVENT_NAME);
+        let clones = Arc::new(AtomicUsize::new(0));
+
+        event.dispatch(CloneCounter(clones.clone())).await.unwrap();
+        assert_eq!(clones.load(Ordering::Relaxed), 0);
+
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        event.dispatch(CloneCounter(clones.clone())).await.unwrap();
+        assert_eq!(clones.load(Ordering::Relaxed), 0);
+
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        event.dispatch(CloneCounter(clones.clone())).await.unwrap();
+        assert_eq!(clones.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn dispatch_with_cancel_skips_all_when_already_cancelled() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        let result = event.dispatch_with_cancel(1, async {}).await;
+        match result {
+            Err(CancellableDispatchError::Cancelled { skipped, errors }) => {
+                assert_eq!(skipped.len(), 2);
+                assert!(errors.is_empty());
+            }
+            other => panic!("Expected a cancelled dispatch, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_with_cancel_completes_without_cancellation() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        event
+            .dispatch_with_cancel(1, std::future::pending())
+            .await
+            .unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn dispatch_with_cancel_skips_remaining_subscribers() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        for _ in 0..2 {
+            event
+                .subscribe_async_closure(
+                    CLOSURE_NAME,
+                    |_data| {
+                        Box::pin(async {
+                            sleep(Duration::from_millis(100)).await;
+                            Ok(())
+                        })
+                    },
+                    false,
+                    false,
+                )
+                .unwrap();
+        }
+
+        let cancel = sleep(Duration::from_millis(150));
+        let result = event.dispatch_with_cancel(1, cancel).await;
+        match result {
+            Err(CancellableDispatchError::Cancelled { skipped, .. }) => {
+                assert_eq!(skipped.len(), 1)
+            }
+            other => panic!("Expected a cancelled dispatch, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn error_handler_receives_errors() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let subscriber_id = event
+            .subscribe_closure(CLOSURE_NAME, |_data| Err("test_error".into()), true, true)
+            .unwrap();
+
+        let handled = Arc::new(AtomicUsize::new(0));
+        let handled_clone = handled.clone();
+        event.set_error_handler(move |error, subscriber| {
+            assert!(matches!(error, DispatchError::Closure(_)));
+            assert_eq!(subscriber.id, subscriber_id);
+            assert_eq!(&*subscriber.name, CLOSURE_NAME);
+            handled_clone.fetch_add(1, Ordering::Relaxed);
+        });
+        assert!(event.has_error_handler());
+
+        let result = event.dispatch(1).await;
+        assert!(result.is_err());
+        assert_eq!(handled.load(Ordering::Relaxed), 1);
+        assert_eq!(event.subscriber_count(), 0);
+
+        assert!(event.remove_error_handler());
+        assert!(!event.has_error_handler());
+    }
+
+    #[tokio::test]
+    async fn prune_subscribers_of_dropped_owners() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let owner = Arc::new(AtomicUsize::new(0));
+
+        let weak_owner = Arc::downgrade(&owner);
+        event
+            .subscribe_closure_owned(
+                CLOSURE_NAME,
+                &owner,
+                move |_data| {
+                    if let Some(owner) = weak_owner.upgrade() {
+                        owner.fetch_add(1, Ordering::Relaxed);
+                    }
+
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        event.dispatch(1).await.unwrap();
+        assert_eq!(owner.load(Ordering::Relaxed), 1);
+        assert_eq!(event.subscriber_count(), 2);
+
+        drop(owner);
+        assert_eq!(event.subscriber_count(), 2); // pruned lazily
+
+        let report = event.dispatch_report(2).await;
+        assert_eq!(report.outcomes.len(), 1);
+        assert_eq!(event.subscriber_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn manage_subscription_through_handler_flow() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let handled = Arc::new(AtomicUsize::new(0));
+        let closure_handled = handled.clone();
+        let id = event
+            .subscribe_flow_closure(
+                CLOSURE_NAME,
+                move |data| {
+                    closure_handled.fetch_add(1, Ordering::Relaxed);
+                    match data {
+                        2 => Ok(HandlerFlow::PauseSelf),
+                        4 => Ok(HandlerFlow::Unsubscribe),
+                        _ => Ok(HandlerFlow::Continue),
+                    }
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        for data in 1..=3 {
+            event.dispatch(data).await.unwrap();
+        }
+        assert_eq!(handled.load(Ordering::Relaxed), 2);
+        assert!(event.is_subscriber_alive(id));
+
+        assert!(event.resume_subscriber(id));
+        event.dispatch(4).await.unwrap();
+        assert_eq!(handled.load(Ordering::Relaxed), 3);
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn upgrade_weak_owner_per_dispatch() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let owner = Arc::new(AtomicUsize::new(0));
+        event
+            .subscribe_closure_weak(
+                CLOSURE_NAME,
+                &owner,
+                |owner, data| {
+                    owner.fetch_add(data as usize, Ordering::Relaxed);
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
+        assert_eq!(Arc::strong_count(&owner), 1);
+
+        event.dispatch(2).await.unwrap();
+        assert_eq!(owner.load(Ordering::Relaxed), 2);
+
+        drop(owner);
+        event.dispatch(3).await.unwrap();
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn reject_subscribers_beyond_max() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event.set_max_subscribers(Some(2));
+
+        let id = event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        let result = event.subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false);
+        assert!(matches!(
+            result,
+            Err(SubscribeError::MaxSubscribersReached {
+                max_subscribers: 2,
+                ..
+            })
+        ));
+        assert_eq!(event.subscriber_count(), 2);
+
+        event.unsubscribe(id);
+        assert!(
+            event
+                .subscribe_channel(CLOSURE_NAME, 1, false, false)
+                .is_ok()
+        );
+
+        let handle = event.handle();
+        let result = handle.subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false);
+        assert!(matches!(result, Err(EventHandleError::Subscribe(_))));
+    }
+
+    #[tokio::test]
+    async fn stateful_closure_mut() {
+        let event = Event::<u64>::new(EVENT_NAME);
+        let total = Arc::new(AtomicU64::new(0));
+
+        let total_for_closure = total.clone();
+        let mut sum = 0;
+        event
+            .subscribe_closure_mut(
+                CLOSURE_NAME,
+                move |data| {
+                    sum += data;
+                    total_for_closure.store(sum, Ordering::Relaxed);
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        for data in 1..=4 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        assert_eq!(total.load(Ordering::Relaxed), 10);
+    }
+
+    #[tokio::test]
+    async fn upgrade_weak_event() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let weak = event.downgrade();
+        let (_, mut receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        let upgraded = weak.upgrade().unwrap();
+        assert!(upgraded == event);
+        upgraded.dispatch(1).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(1));
+
+        drop(upgraded);
+        drop(event);
+        assert!(weak.is_dropped());
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[tokio::test]
+    async fn watch_subscriber_count() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let mut count_watch = event.subscriber_count_watch();
+        assert_eq!(*count_watch.borrow_and_update(), 0);
+
+        let id = event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        count_watch.changed().await.unwrap();
+        assert_eq!(*count_watch.borrow_and_update(), 1);
+
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Err("test_error".into()), false, true)
+            .unwrap();
+        event.unsubscribe(id);
+        let _ = event.dispatch(1).await;
+        count_watch.changed().await.unwrap();
+        assert_eq!(*count_watch.borrow_and_update(), 0);
+    }
+
+    #[tokio::test]
+    async fn flush_waits_for_channel_consumers() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 10, false, false)
+            .unwrap();
+        let (_, dropped_receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 10, false, false)
+            .unwrap();
+        drop(dropped_receiver);
+
+        for data in 0..5 {
+            let _ = event.dispatch(data).await;
+        }
+
+        let consumed = Arc::new(AtomicUsize::new(0));
+        let consumed_by_task = consumed.clone();
+        tokio::spawn(async move {
+            while receiver.recv().await.is_some() {
+                sleep(Duration::from_millis(5)).await;
+                consumed_by_task.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        event.flush().await;
+        assert!(consumed.load(Ordering::SeqCst) >= 4);
+    }
+
+    #[tokio::test]
+    async fn time_out_slow_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let subscriber = Subscriber::builder(CLOSURE_NAME)
+            .log_on_error(false)
+            .remove_on_error(true)
+            .timeout(Duration::from_millis(20))
+            .async_closure(|_data| {
+                Box::pin(async {
+                    sleep(Duration::from_secs(10)).await;
+                    Ok(())
+                })
+            });
+        let slow_id = event.subscribe(subscriber).unwrap();
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        let report = event.dispatch_report(1).await;
+        assert_eq!(report.delivered_count(), 1);
+
+        let removed: Vec<u64> = report.removed().map(|subscriber| subscriber.id).collect();
+        assert_eq!(removed, vec![slow_id]);
+        assert!(report.outcomes.iter().any(|subscriber_outcome| matches!(
+            subscriber_outcome.outcome.error(),
+            Some(DispatchError::Timeout(_))
+        )));
+        assert_eq!(event.subscriber_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn spawned_closure_does_not_block_dispatch() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (sender, mut receiver) = lum_libs::tokio::sync::mpsc::unbounded_channel();
+        event
+            .subscribe_spawned_closure(
+                CLOSURE_NAME,
+                move |data| {
+                    let sender = sender.clone();
+                    Box::pin(async move {
+                        sleep(Duration::from_millis(100)).await;
+                        sender.send(data)?;
+                        Ok(())
+                    })
+                },
+                false,
+            )
+            .unwrap();
+
+        let start = Instant::now();
+        event.dispatch(1).await.unwrap();
+        assert!(start.elapsed() < Duration::from_millis(100));
+        assert_eq!(receiver.recv().await, Some(1));
+    }
+
+    #[tokio::test]
+    async fn blocking_closure_runs_off_executor() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let ticked = Arc::new(AtomicBool::new(false));
+
+        let ticker = ticked.clone();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(10)).await;
+            ticker.store(true, Ordering::SeqCst);
+        });
+
+        let observed = ticked.clone();
+        event
+            .subscribe_blocking_closure(
+                CLOSURE_NAME,
+                move |_data| {
+                    std::thread::sleep(Duration::from_millis(100));
+                    match observed.load(Ordering::SeqCst) {
+                        true => Ok(()),
+                        false => Err("The executor was blocked".into()),
+                    }
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        event.dispatch(1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn identify_failing_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        let failing_id = event
+            .subscribe_closure("failing", |_data| Err("test_error".into()), false, false)
+            .unwrap();
+
+        let errors = event.dispatch(1).await.unwrap_err();
+        assert_eq!(errors.len(), 1);
+
+        let subscriber = errors[0].subscriber.as_ref().unwrap();
+        assert_eq!(subscriber.id, failing_id);
+        assert_eq!(&*subscriber.name, "failing");
+        assert!(matches!(errors[0].error, DispatchError::Closure(_)));
+        assert!(errors[0].to_string().starts_with("Subscriber \"failing\""));
+    }
+
+    #[cfg(feature = "crossbeam")]
+    #[tokio::test]
+    async fn send_to_crossbeam_without_blocking() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        event
+            .subscribe_crossbeam(CLOSURE_NAME, sender, false, false)
+            .unwrap();
+
+        event.dispatch(1).await.unwrap();
+        let errors = event.dispatch(2).await.unwrap_err();
+        assert!(matches!(
+            errors[0].error,
+            DispatchError::CrossbeamSend(crossbeam_channel::TrySendError::Full(2))
+        ));
+        assert_eq!(receiver.try_recv(), Ok(1));
+
+        drop(receiver);
+        let errors = event.dispatch(3).await.unwrap_err();
+        assert!(matches!(
+            errors[0].error,
+            DispatchError::CrossbeamSend(crossbeam_channel::TrySendError::Disconnected(3))
+        ));
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn feed_sink() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let sink = futures_util::sink::unfold(sender, |sender, data| async move {
+            sender.send(data)?;
+            Ok::<_, BoxedError>(sender)
+        });
+
+        event
+            .subscribe_sink(CLOSURE_NAME, sink, false, true)
+            .unwrap();
+        event.dispatch(1).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(1));
+
+        drop(receiver);
+        let errors = event.dispatch(2).await.unwrap_err();
+        assert!(matches!(errors[0].error, DispatchError::Sink(_)));
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn replay_into_sink() {
+        let event = Event::<u8>::builder(EVENT_NAME).replay(2).build();
+        event.dispatch(1).await.unwrap();
+        event.dispatch(2).await.unwrap();
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let sink = futures_util::sink::unfold(sender, |sender, data| async move {
+            sender.send(data)?;
+            Ok::<_, BoxedError>(sender)
+        });
+        event
+            .subscribe_sink(CLOSURE_NAME, sink, false, false)
+            .unwrap();
+
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn probe_channel_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (id, receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 10, false, false)
+            .unwrap();
+        assert!(event.is_subscriber_alive(id));
+
+        drop(receiver);
+        assert!(!event.is_subscriber_alive(id));
+        assert!(!event.is_subscriber_alive(id + 1));
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_group() {
+        const GROUP: &str = "test_group";
+        const OTHER_GROUP: &str = "other_test_group";
+
+        let event = Event::<u8>::new(EVENT_NAME);
+        for _ in 0..3 {
+            let subscriber = Subscriber::new(
+                CLOSURE_NAME,
+                false,
+                false,
+                Callback::Closure(Box::new(|_data| Ok(()))),
+            )
+            .with_group(GROUP);
+            event.subscribe(subscriber).unwrap();
+        }
+
+        let other = Subscriber::new(
+            CLOSURE_NAME,
+            false,
+            false,
+            Callback::Closure(Box::new(|_data| Ok(()))),
+        )
+        .with_group(OTHER_GROUP);
+        event.subscribe(other).unwrap();
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        assert_eq!(event.group_subscriber_count(GROUP), 3);
+        assert_eq!(event.unsubscribe_group(GROUP), 3);
+        assert_eq!(event.group_subscriber_count(GROUP), 0);
+        assert_eq!(event.subscriber_count(), 2);
+        assert_eq!(event.unsubscribe_group(GROUP), 0);
+    }
+
+    #[tokio::test]
+    async fn subscriber_can_unsubscribe_itself_during_dispatch() {
+        let event = Event::<()>::new(EVENT_NAME);
+        let id = Arc::new(AtomicU64::new(0));
+
+        let handle = event.handle();
+        let id_clone = id.clone();
+        let subscriber_id = event
+            .subscribe_closure(
+                CLOSURE_NAME,
+                move |_data| {
+                    handle
+                        .unsubscribe(id_clone.load(Ordering::Relaxed))
+                        .unwrap();
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
+        id.store(subscriber_id, Ordering::Relaxed);
+
+        event.dispatch(()).await.unwrap();
+        assert_eq!(event.subscriber_count(), 0);
     }
 }
@@ -0,0 +1,406 @@
+use std::{marker::PhantomData, num::NonZeroUsize, sync::Arc};
+
+use lum_boxtypes::BoxedError;
+use lum_libs::tokio::sync::Semaphore;
+
+use crate::{
+    Event,
+    rate_limit::RateLimit,
+    runtime::{Runtime, default_runtime},
+    slow_consumer::SlowConsumerDetection,
+    subscriber::CallbackError,
+};
+
+pub const DEFAULT_CHANNEL_BUFFER: usize = 16;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DispatchMode {
+    #[default]
+    Sequential,
+    Concurrent,
+}
+
+// Used by the subscribe_*_with_defaults methods instead of per-call flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscribeDefaults {
+    pub channel_buffer: usize,
+    pub log_on_error: bool,
+    pub remove_on_error: bool,
+}
+
+impl Default for SubscribeDefaults {
+    fn default() -> Self {
+        Self {
+            channel_buffer: DEFAULT_CHANNEL_BUFFER,
+            log_on_error: true,
+            remove_on_error: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EventBuilder<T: Clone + Send, E: CallbackError = BoxedError> {
+    pub(crate) name: String,
+    pub(crate) subscribe_defaults: SubscribeDefaults,
+    pub(crate) dispatch_mode: DispatchMode,
+    pub(crate) max_concurrency: Option<usize>,
+    pub(crate) ordered: bool,
+    pub(crate) replay: usize,
+    pub(crate) metrics: bool,
+    pub(crate) max_subscribers: Option<usize>,
+    pub(crate) rate_limit: Option<RateLimit>,
+    pub(crate) slow_consumer_detection: Option<SlowConsumerDetection>,
+    pub(crate) runtime: Arc<dyn Runtime>,
+    _data: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T: Clone + Send, E: CallbackError> EventBuilder<T, E> {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            subscribe_defaults: SubscribeDefaults::default(),
+            dispatch_mode: DispatchMode::default(),
+            max_concurrency: None,
+            ordered: false,
+            replay: 0,
+            metrics: true,
+            max_subscribers: None,
+            rate_limit: None,
+            slow_consumer_detection: None,
+            runtime: default_runtime(),
+            _data: PhantomData,
+        }
+    }
+
+    pub fn channel_buffer(mut self, buffer: usize) -> Self {
+        self.subscribe_defaults.channel_buffer = buffer;
+        self
+    }
+
+    pub fn log_on_error(mut self, log_on_error: bool) -> Self {
+        self.subscribe_defaults.log_on_error = log_on_error;
+        self
+    }
+
+    pub fn remove_on_error(mut self, remove_on_error: bool) -> Self {
+        self.subscribe_defaults.remove_on_error = remove_on_error;
+        self
+    }
+
+    pub fn dispatch_mode(mut self, dispatch_mode: DispatchMode) -> Self {
+        self.dispatch_mode = dispatch_mode;
+        self
+    }
+
+    // Limits how many subscriber callbacks of this event run at the same time, across all dispatches.
+    // Limits above Semaphore::MAX_PERMITS are clamped to it.
+    pub fn max_concurrency(mut self, max_concurrency: NonZeroUsize) -> Self {
+        self.max_concurrency = Some(max_concurrency.get().min(Semaphore::MAX_PERMITS));
+        self
+    }
+
+    // Dispatches reach subscribers by priority and then in the order they subscribed instead of map order
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
+    // New subscribers receive up to this many of the most recently dispatched values
+    pub fn replay(mut self, count: usize) -> Self {
+        self.replay = count;
+        self
+    }
+
+    pub fn metrics(mut self, enabled: bool) -> Self {
+        self.metrics = enabled;
+        self
+    }
+
+    pub fn max_subscribers(mut self, max_subscribers: usize) -> Self {
+        self.max_subscribers = Some(max_subscribers);
+        self
+    }
+
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    // Channel subscribers that stay near capacity are logged or reported to the slow consumer handler
+    pub fn slow_consumer_detection(mut self, detection: SlowConsumerDetection) -> Self {
+        self.slow_consumer_detection = Some(detection);
+        self
+    }
+
+    // Used for replays to async closures, queued rate limits, scheduled dispatches and request timeouts
+    pub fn runtime(mut self, runtime: impl Runtime) -> Self {
+        self.runtime = Arc::new(runtime);
+        self
+    }
+
+    pub fn build(self) -> Event<T, E> {
+        Event::from_builder(self)
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Clone for EventBuilder<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            subscribe_defaults: self.subscribe_defaults,
+            dispatch_mode: self.dispatch_mode,
+            max_concurrency: self.max_concurrency,
+            ordered: self.ordered,
+            replay: self.replay,
+            metrics: self.metrics,
+            max_subscribers: self.max_subscribers,
+            rate_limit: self.rate_limit,
+            slow_consumer_detection: self.slow_consumer_detection,
+            runtime: self.runtime.clone(),
+            _data: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::Subscriber;
+    use lum_libs::{
+        parking_lot::Mutex,
+        tokio::{self, time::sleep},
+    };
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[tokio::test]
+    async fn apply_subscribe_defaults() {
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .channel_buffer(2)
+            .log_on_error(false)
+            .remove_on_error(true)
+            .max_subscribers(1)
+            .build();
+
+        let (_, mut receiver) = event
+            .subscribe_channel_with_defaults(SUBSCRIBER_NAME)
+            .unwrap();
+        event.dispatch(1).await.unwrap();
+        event.dispatch(2).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+
+        assert!(
+            event
+                .subscribe_closure_with_defaults(SUBSCRIBER_NAME, |_data| Ok(()))
+                .is_err()
+        );
+
+        drop(receiver);
+        assert!(event.dispatch(3).await.is_err());
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn replay_recent_values() {
+        let event = Event::<u8>::builder(EVENT_NAME).replay(2).build();
+        for data in 1..=3 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        let (_, mut receiver) = event
+            .subscribe_channel_with_defaults(SUBSCRIBER_NAME)
+            .unwrap();
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, Some(3));
+
+        event.dispatch(4).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(4));
+    }
+
+    #[tokio::test]
+    async fn subscribe_from_replayed_closure() {
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .replay(1)
+            .max_subscribers(2)
+            .build();
+        event.dispatch(1).await.unwrap();
+
+        let event_handle = event.handle();
+        event
+            .subscribe_closure_with_defaults(SUBSCRIBER_NAME, move |_data| {
+                event_handle.subscribe_closure(SUBSCRIBER_NAME, |_data| Ok(()), false, false)?;
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(event.subscriber_count(), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn replay_without_gaps_or_duplicates_during_dispatch() {
+        const COUNT: u16 = 2000;
+
+        let event = Event::<u16>::builder(EVENT_NAME)
+            .replay(COUNT as usize)
+            .build();
+        let event_handle = event.handle();
+        let dispatcher = tokio::spawn(async move {
+            for data in 0..COUNT {
+                event_handle.dispatch(data).await.unwrap().unwrap();
+            }
+        });
+
+        sleep(Duration::from_millis(1)).await;
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let subscriber_received = received.clone();
+        event
+            .subscribe_closure_with_defaults(SUBSCRIBER_NAME, move |data| {
+                subscriber_received.lock().push(data);
+                Ok(())
+            })
+            .unwrap();
+
+        dispatcher.await.unwrap();
+        assert_eq!(*received.lock(), (0..COUNT).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn dispatch_concurrently() {
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .dispatch_mode(DispatchMode::Concurrent)
+            .build();
+
+        let delivered = Arc::new(AtomicUsize::new(0));
+        for _ in 0..3 {
+            let delivered = delivered.clone();
+            event
+                .subscribe_async_closure_with_defaults(SUBSCRIBER_NAME, move |_data| {
+                    let delivered = delivered.clone();
+                    Box::pin(async move {
+                        sleep(Duration::from_millis(100)).await;
+                        delivered.fetch_add(1, Ordering::Relaxed);
+                        Ok(())
+                    })
+                })
+                .unwrap();
+        }
+
+        let start = tokio::time::Instant::now();
+        event.dispatch(1).await.unwrap();
+        assert!(start.elapsed() < Duration::from_millis(250));
+        assert_eq!(delivered.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn clamp_max_concurrency_to_semaphore_limit() {
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .max_concurrency(NonZeroUsize::MAX)
+            .build();
+
+        assert_eq!(event.max_concurrency(), Some(Semaphore::MAX_PERMITS));
+    }
+
+    #[tokio::test]
+    async fn limit_concurrent_callbacks() {
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .dispatch_mode(DispatchMode::Concurrent)
+            .max_concurrency(NonZeroUsize::new(2).unwrap())
+            .build();
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        for _ in 0..8 {
+            let running = running.clone();
+            let max_running = max_running.clone();
+            event
+                .subscribe_async_closure_with_defaults(SUBSCRIBER_NAME, move |_data| {
+                    let running = running.clone();
+                    let max_running = max_running.clone();
+                    Box::pin(async move {
+                        let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_running.fetch_max(now_running, Ordering::SeqCst);
+                        sleep(Duration::from_millis(10)).await;
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                })
+                .unwrap();
+        }
+
+        event.dispatch(1).await.unwrap();
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn dispatch_in_subscription_order() {
+        let event = Event::<u8>::builder(EVENT_NAME).ordered(true).build();
+
+        let ids: Vec<u64> = (0..32)
+            .map(|_| {
+                event
+                    .subscribe_closure_with_defaults(SUBSCRIBER_NAME, |_data| Ok(()))
+                    .unwrap()
+            })
+            .collect();
+
+        let report = event.dispatch_report(1).await;
+        let reached: Vec<u64> = report
+            .outcomes
+            .iter()
+            .map(|subscriber_outcome| subscriber_outcome.subscriber.id)
+            .collect();
+
+        assert_eq!(reached, ids);
+    }
+
+    #[tokio::test]
+    async fn disable_metrics() {
+        let event = Event::<u8>::builder(EVENT_NAME).metrics(false).build();
+        event.dispatch(1).await.unwrap();
+
+        assert_eq!(event.stats().total_dispatches, 0);
+        assert!(!event.metrics_enabled());
+    }
+
+    #[tokio::test]
+    async fn dispatch_by_priority() {
+        let event = Event::<u8>::builder(EVENT_NAME).ordered(true).build();
+
+        let low = event
+            .subscribe_closure_with_defaults(SUBSCRIBER_NAME, |_data| Ok(()))
+            .unwrap();
+        let high = event
+            .subscribe(
+                Subscriber::builder(SUBSCRIBER_NAME)
+                    .priority(10)
+                    .closure(|_data| Ok(())),
+            )
+            .unwrap();
+        let default = event
+            .subscribe_closure_with_defaults(SUBSCRIBER_NAME, |_data| Ok(()))
+            .unwrap();
+        assert!(event.set_subscriber_priority(low, -1));
+
+        let report = event.dispatch_report(1).await;
+        let reached: Vec<(u64, i32)> = report
+            .outcomes
+            .iter()
+            .map(|subscriber_outcome| {
+                let subscriber = &subscriber_outcome.subscriber;
+                (subscriber.id, subscriber.priority)
+            })
+            .collect();
+
+        assert_eq!(reached, vec![(high, 10), (default, 0), (low, -1)]);
+    }
+}
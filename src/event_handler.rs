@@ -0,0 +1,69 @@
+use lum_boxtypes::{BoxedError, LifetimedPinnedBoxedFuture};
+
+// For long-lived handler objects that keep state and are used elsewhere as well.
+// Subscribe an Arc of the handler to keep a reference to it.
+pub trait EventHandler<T, E = BoxedError>: Send + Sync {
+    fn handle<'a>(&'a self, data: T) -> LifetimedPinnedBoxedFuture<'a, Result<(), E>>
+    where
+        T: 'a;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicU64, Ordering},
+        },
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::Event;
+    use lum_boxtypes::LifetimedPinnedBoxedFutureResult;
+    use lum_libs::tokio::{self, task::yield_now, time::sleep};
+
+    const EVENT_NAME: &str = "test_event";
+    const HANDLER_NAME: &str = "test_handler";
+
+    #[derive(Default)]
+    struct SumHandler {
+        sum: AtomicU64,
+    }
+
+    impl SumHandler {
+        fn sum(&self) -> u64 {
+            self.sum.load(Ordering::Relaxed)
+        }
+    }
+
+    impl EventHandler<u8> for SumHandler {
+        fn handle<'a>(&'a self, data: u8) -> LifetimedPinnedBoxedFutureResult<'a, ()>
+        where
+            u8: 'a,
+        {
+            Box::pin(async move {
+                yield_now().await;
+                self.sum.fetch_add(data as u64, Ordering::Relaxed);
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_to_handler() {
+        let event = Event::<u8>::builder(EVENT_NAME).replay(1).build();
+        event.dispatch(1).await.unwrap();
+
+        let handler = Arc::new(SumHandler::default());
+        event
+            .subscribe_handler(HANDLER_NAME, handler.clone(), false, false)
+            .unwrap();
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(handler.sum(), 1);
+
+        event.dispatch(2).await.unwrap();
+        event.dispatch(3).await.unwrap();
+        assert_eq!(handler.sum(), 6);
+    }
+}
@@ -1,10 +1,7 @@
 use lum_boxtypes::BoxedError;
 use lum_libs::{
     dashmap::DashMap,
-    tokio::{
-        spawn,
-        sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
-    },
+    tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
 };
 use std::{
     fmt::{self, Display, Formatter},
@@ -12,7 +9,11 @@ use std::{
 };
 use thiserror::Error;
 
-use crate::event::EventHandleError;
+use crate::{
+    event::{EventHandleError, SubscribeError},
+    runtime::{Runtime, TokioRuntime},
+    trace::traced,
+};
 
 use super::{Event, event::EventHandle};
 
@@ -26,6 +27,9 @@ pub enum AttachError {
     #[error("The EventHandle hit an error while attaching: {0}")]
     EventHandle(#[from] EventHandleError),
 
+    #[error("The event rejected the EventRepeater's subscriber: {0}")]
+    Subscribe(#[from] SubscribeError),
+
     #[error(
         "Tried to attach EventRepeater {event_repeater_name} to Event {event_name}, which it was already attached to"
     )]
@@ -67,6 +71,10 @@ impl<T: Clone + Send + 'static> EventRepeater<T, T> {
     pub fn new(name: impl Into<String>) -> Self {
         Self::new_with_transform(name, |data| data)
     }
+
+    pub fn new_with_runtime(name: impl Into<String>, runtime: impl Runtime) -> Self {
+        Self::new_with_transform_and_runtime(name, |data| data, runtime)
+    }
 }
 
 impl<IN: Clone + Send + 'static, OUT: Clone + Send + 'static> EventRepeater<IN, OUT> {
@@ -74,15 +82,23 @@ impl<IN: Clone + Send + 'static, OUT: Clone + Send + 'static> EventRepeater<IN,
         name: impl Into<String>,
         transform: impl Fn(IN) -> OUT + Send + 'static,
     ) -> Self {
-        let event = Event::new(name);
+        Self::new_with_transform_and_runtime(name, transform, TokioRuntime)
+    }
+
+    pub fn new_with_transform_and_runtime(
+        name: impl Into<String>,
+        transform: impl Fn(IN) -> OUT + Send + 'static,
+        runtime: impl Runtime,
+    ) -> Self {
+        let event = Event::builder(name).runtime(runtime).build();
         let event_handle = event.handle();
 
         let attachments = Arc::new(DashMap::new());
         let alive = Arc::new(());
         let (event_queue_sender, event_queue_receiver) = unbounded_channel::<IN>();
-        spawn(async move {
+        event.runtime().spawn(Box::pin(async move {
             run_forward_loop(event_handle, event_queue_receiver, Box::new(transform)).await;
-        });
+        }));
 
         Self {
             event,
@@ -136,7 +152,7 @@ impl<IN: Clone + Send + 'static, OUT: Clone + Send + 'static> EventRepeater<IN,
                 },
                 false,
                 true,
-            );
+            )?;
 
             let handle_for_is_dropped = event_handle.clone();
             let handle_for_unsubscribe = event_handle.clone();
@@ -193,7 +209,7 @@ impl<IN: Clone + Send + 'static, OUT: Clone + Send + 'static> EventRepeater<IN,
                 },
                 false,
                 true,
-            );
+            )?;
 
             let handle_for_is_dropped = event_handle.clone();
             let handle_for_unsubscribe = event_handle.clone();
@@ -260,7 +276,14 @@ async fn run_forward_loop<IN: Clone + Send + 'static, OUT: Clone + Send + 'stati
 ) {
     while let Some(data) = event_queue_receiver.recv().await {
         let out = transform(data);
-        if event_handle.dispatch(out).await.is_err() {
+        let forward = traced!(
+            event_handle.dispatch(out),
+            "forward",
+            payload_in = std::any::type_name::<IN>(),
+            payload_out = std::any::type_name::<OUT>(),
+        );
+
+        if forward.await.is_err() {
             return;
         }
     }
@@ -314,7 +337,7 @@ mod tests {
     use std::time::Duration;
 
     use super::*;
-    use lum_libs::tokio::{self, time::sleep};
+    use lum_libs::tokio::{self, spawn, time::sleep};
 
     const REPEATER_NAME: &str = "test_repeater";
     const EVENT_NAME: &str = "test_event";
@@ -456,6 +479,7 @@ mod tests {
         let mut receiver = event_repeater
             .event
             .subscribe_channel(RECEIVER_NAME, 1, false, true)
+            .unwrap()
             .1;
 
         event1.dispatch(DATA).await.unwrap();
@@ -476,6 +500,7 @@ mod tests {
         let mut receiver = event_repeater
             .event
             .subscribe_channel(RECEIVER_NAME, 1, false, true)
+            .unwrap()
             .1;
 
         event1.dispatch("hello".to_string()).await.unwrap();
@@ -495,6 +520,7 @@ mod tests {
         let mut receiver = event_repeater
             .event
             .subscribe_channel(RECEIVER_NAME, 1, false, true)
+            .unwrap()
             .1;
 
         event1.dispatch(DATA).await.unwrap();
@@ -515,6 +541,7 @@ mod tests {
         let mut receiver = event_repeater
             .event
             .subscribe_channel(RECEIVER_NAME, 1, false, true)
+            .unwrap()
             .1;
 
         event1.dispatch("hello".to_string()).await.unwrap();
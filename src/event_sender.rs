@@ -0,0 +1,146 @@
+use std::{
+    any::type_name,
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Weak},
+};
+
+use lum_boxtypes::BoxedError;
+
+use crate::{
+    Event,
+    dispatch_report::DispatchReport,
+    event::{CancellableDispatchError, EventHandleError, EventInner},
+    subscriber::{CallbackError, DispatchFailure},
+};
+
+// Dispatch-only capability for producers, it can't subscribe, unsubscribe or reconfigure the event
+pub struct EventSender<T: Clone + Send, E: CallbackError = BoxedError> {
+    inner: Weak<EventInner<T, E>>,
+}
+
+impl<T: Clone + Send, E: CallbackError> Clone for EventSender<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> EventSender<T, E> {
+    pub fn id(&self) -> Result<u64, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let id = inner.id();
+
+        Ok(id)
+    }
+
+    pub fn name(&self) -> Result<String, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let name = inner.name().to_string();
+
+        Ok(name)
+    }
+
+    pub async fn dispatch(
+        &self,
+        data: T,
+    ) -> Result<Result<(), Vec<DispatchFailure<T, E>>>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let result = inner.dispatch(data).await;
+
+        Ok(result)
+    }
+
+    pub async fn dispatch_report(&self, data: T) -> Result<DispatchReport<T, E>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let report = inner.dispatch_report(data).await;
+
+        Ok(report)
+    }
+
+    pub async fn dispatch_with_cancel(
+        &self,
+        data: T,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<Result<(), CancellableDispatchError<T, E>>, EventHandleError> {
+        let inner = self.inner.upgrade().ok_or(EventHandleError::EventDropped)?;
+        let result = inner.dispatch_with_cancel(data, cancel).await;
+
+        Ok(result)
+    }
+
+    pub fn is_dropped(&self) -> bool {
+        self.inner.strong_count() == 0
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Event<T, E> {
+    pub fn sender(&self) -> EventSender<T, E> {
+        EventSender {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> From<&Event<T, E>> for EventSender<T, E> {
+    fn from(event: &Event<T, E>) -> Self {
+        event.sender()
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> PartialEq for EventSender<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.ptr_eq(&other.inner)
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Debug for EventSender<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.inner.upgrade() {
+            Some(inner) => <EventInner<T, E> as Debug>::fmt(&inner, f),
+            None => f
+                .debug_struct(type_name::<Self>())
+                .field("inner", &"dropped")
+                .finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+
+    #[tokio::test]
+    async fn dispatch_through_sender() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        let sender = event.sender();
+        let cloned_sender = sender.clone();
+        sender.dispatch(1).await.unwrap().unwrap();
+        cloned_sender.dispatch(2).await.unwrap().unwrap();
+
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(sender.id().unwrap(), event.id());
+    }
+
+    #[tokio::test]
+    async fn sender_does_not_keep_event_alive() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let sender = EventSender::from(&event);
+        drop(event);
+
+        assert!(sender.is_dropped());
+        assert!(matches!(
+            sender.dispatch(1).await,
+            Err(EventHandleError::EventDropped)
+        ));
+    }
+}
@@ -1,13 +1,70 @@
 pub(crate) mod id;
+pub(crate) mod trace;
 
 pub mod arc_observable;
+pub mod batch;
+pub mod coalescing_event;
+pub mod combinator;
+pub mod custom_callback;
+pub mod dead_letter;
+pub mod debounced_event;
+pub mod derived_observable;
+pub mod dispatch_report;
 pub mod event;
+pub mod event_builder;
+pub mod event_handler;
 pub mod event_repeater;
+pub mod event_sender;
 pub mod observable;
+pub mod observable_cell;
+pub mod observable_lens;
+pub mod observable_map;
+pub mod observable_reader;
+pub mod observable_set;
+pub mod observable_writer;
+#[cfg(feature = "serde")]
+pub mod persistence;
+pub mod rate_limit;
+pub mod relay;
+pub mod request;
+pub mod retry;
+pub mod router;
+pub mod runtime;
+pub mod scheduled_dispatch;
+#[cfg(feature = "serde")]
+pub mod serialize_writer;
+pub mod slow_consumer;
+pub mod stats;
 pub mod subscriber;
+pub mod subscriber_builder;
+pub mod subscription_guard;
+#[cfg(feature = "sync")]
+pub mod sync_event;
+pub mod throttled_event;
 
 pub use arc_observable::ArcObservable;
+pub use coalescing_event::CoalescingEvent;
+pub use custom_callback::CustomCallback;
+pub use debounced_event::DebouncedEvent;
+pub use derived_observable::DerivedObservable;
 pub use event::Event;
+pub use event_builder::EventBuilder;
+pub use event_handler::EventHandler;
 pub use event_repeater::EventRepeater;
+pub use event_sender::EventSender;
 pub use observable::Observable;
+pub use observable_cell::ObservableCell;
+pub use observable_lens::ObservableLens;
+pub use observable_map::ObservableHashMap;
+pub use observable_reader::ObservableReader;
+pub use observable_set::ObservableSet;
+pub use observable_writer::ObservableWriter;
+pub use router::EventRouter;
+#[cfg(feature = "serde")]
+pub use serialize_writer::SerializeWriter;
 pub use subscriber::Subscriber;
+pub use subscriber_builder::SubscriberBuilder;
+pub use subscription_guard::SubscriptionGuard;
+#[cfg(feature = "sync")]
+pub use sync_event::SyncEvent;
+pub use throttled_event::ThrottledEvent;
@@ -1,41 +1,690 @@
 use core::result::Result as CoreResult;
-use std::hash::{Hash, Hasher};
+use std::{
+    any::{Any, type_name},
+    collections::{BTreeSet, VecDeque},
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
-use crate::{Event, subscriber::DispatchError};
+use lum_boxtypes::BoxedError;
+use lum_libs::{
+    parking_lot::{Mutex, RwLock},
+    tokio::sync::{
+        Notify,
+        mpsc::{Receiver, channel},
+        watch,
+    },
+};
+use lum_log::error;
+
+#[cfg(feature = "futures")]
+use futures_util::{Stream, stream};
+#[cfg(feature = "serde")]
+use lum_libs::serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+
+#[cfg(feature = "serde")]
+use crate::persistence::{Persistence, PersistenceError};
+use crate::{
+    DebouncedEvent, DerivedObservable, Event, ObservableLens, ObservableReader, ObservableWriter,
+    Subscriber, ThrottledEvent,
+    event::SubscribeError,
+    subscriber::{Callback, DispatchFailure, HandlerFlow},
+    throttled_event::ThrottleMode,
+};
+
+pub type Validator<T> = dyn Fn(&T) -> CoreResult<(), BoxedError> + Send + Sync;
+pub type Comparator<T> = dyn Fn(&T, &T) -> bool + Send + Sync;
+// Current value, subscriber id and receiver of get_and_subscribe_channel
+pub type SubscribedValue<T> = (T, u64, Receiver<T>);
 
 #[derive(Debug)]
 pub enum Result<T> {
     Unchanged,
-    Changed(CoreResult<(), Vec<DispatchError<T>>>),
+    Changed(CoreResult<(), Vec<DispatchFailure<T>>>),
+    // The validator refused the value, so nothing changed or dispatched
+    Rejected(BoxedError),
 }
 
+// Payload of Observable::on_transition
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+// Working copy of an Observable's value inside Observable::transaction
 #[derive(Debug)]
-pub struct Observable<T: Clone + Send + PartialEq> {
+pub struct Transaction<T> {
+    value: T,
+}
+
+impl<T> Transaction<T> {
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    pub fn set(&mut self, value: T) {
+        self.value = value;
+    }
+
+    pub fn update(&mut self, update: impl FnOnce(&mut T)) {
+        update(&mut self.value);
+    }
+}
+
+#[derive(Debug)]
+struct History<T> {
+    capacity: usize,
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+}
+
+impl<T: Clone> History<T> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    // A new change invalidates everything that could have been redone
+    fn record(&mut self, old: T) {
+        self.redo.clear();
+        self.push_undo(old);
+    }
+
+    fn push_undo(&mut self, value: T) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(value);
+    }
+
+    fn undo(&mut self, current: &T) -> Option<T> {
+        let value = self.undo.pop_back()?;
+        self.redo.push(current.clone());
+        Some(value)
+    }
+
+    fn redo(&mut self, current: &T) -> Option<T> {
+        let value = self.redo.pop()?;
+        self.push_undo(current.clone());
+        Some(value)
+    }
+}
+
+// Lets the notifications of concurrent changes run one after another, in the order of their
+// versions. A notification that is dropped before its turn is skipped once its turn comes.
+#[derive(Debug)]
+struct NotifyOrder {
+    // The next version to notify, followed by versions that finished or were dropped early
+    turns: Mutex<(u64, BTreeSet<u64>)>,
+    turn_notify: Notify,
+}
+
+impl NotifyOrder {
+    fn new(version: u64) -> Self {
+        Self {
+            turns: Mutex::new((version + 1, BTreeSet::new())),
+            turn_notify: Notify::new(),
+        }
+    }
+
+    async fn wait_for_turn(&self, version: u64) -> NotifyTurn<'_> {
+        let turn = NotifyTurn {
+            order: self,
+            version,
+        };
+
+        loop {
+            // Created before the check, so a turn that ends in between still wakes it
+            let turn_ended = self.turn_notify.notified();
+            if self.turns.lock().0 == version {
+                return turn;
+            }
+
+            turn_ended.await;
+        }
+    }
+
+    fn finish(&self, version: u64) {
+        {
+            let (next, finished) = &mut *self.turns.lock();
+            finished.insert(version);
+            while finished.remove(next) {
+                *next += 1;
+            }
+        }
+
+        self.turn_notify.notify_waiters();
+    }
+}
+
+// Ends the turn when the notification completes or is dropped
+struct NotifyTurn<'a> {
+    order: &'a NotifyOrder,
+    version: u64,
+}
+
+impl Drop for NotifyTurn<'_> {
+    fn drop(&mut self) {
+        self.order.finish(self.version);
+    }
+}
+
+// Decides whether setting a value counts as a change
+pub enum ChangeDetection<T> {
+    // Every set dispatches, even if the value is the same
+    Always,
+    // Returns true if the two values are equal, which makes the set a no-op
+    Equal(fn(&T, &T) -> bool),
+    // Like Equal, but the comparator can capture state
+    EqualBy(Box<Comparator<T>>),
+}
+
+impl<T: PartialEq> ChangeDetection<T> {
+    pub fn equality() -> Self {
+        ChangeDetection::Equal(T::eq)
+    }
+}
+
+impl<T> ChangeDetection<T> {
+    fn is_unchanged(&self, current: &T, value: &T) -> bool {
+        match self {
+            ChangeDetection::Always => false,
+            ChangeDetection::Equal(equal) => equal(current, value),
+            ChangeDetection::EqualBy(equal) => equal(current, value),
+        }
+    }
+}
+
+impl<T> Debug for ChangeDetection<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeDetection::Always => f.write_str("Always"),
+            ChangeDetection::Equal(_) => f.write_str("Equal"),
+            ChangeDetection::EqualBy(_) => f.write_str("EqualBy"),
+        }
+    }
+}
+
+// The value sits behind a mutex like in ArcObservable, so an Observable can be shared and set
+// through a shared reference. The lock is never held across a dispatch.
+pub struct Observable<T: Clone + Send> {
     pub on_change: Event<T>,
+    pub on_transition: Event<Change<T>>,
 
-    value: T,
+    value: Mutex<T>,
+    change_detection: ChangeDetection<T>,
+    validator: RwLock<Option<Box<Validator<T>>>>,
+    history: Mutex<Option<History<T>>>,
+    // Only changed while the value is locked, so the two always match
+    version: AtomicU64,
+    // Bumped by every set_expiring, so only the latest one reverts the value
+    expiry: AtomicU64,
+    // Set while the init future of Observable::new_async runs, init_notify wakes ready once it's done
+    init_pending: AtomicBool,
+    init_notify: Notify,
+    // Wakes everyone waiting for the next change
+    change_notify: Notify,
+    notify_order: NotifyOrder,
 }
 
 impl<T: Clone + Send + PartialEq> Observable<T> {
     pub fn new(value: T, event_name: impl Into<String>) -> Self {
-        Self {
+        Self::with_change_detection(value, event_name, ChangeDetection::equality())
+    }
+
+    // Returns None without dispatching if the current value is not the expected one.
+    // The comparison and the swap happen under the same lock.
+    pub async fn compare_and_set(&self, expected: &T, value: T) -> Option<Result<T>> {
+        let (old, version) = {
+            let mut current_value = self.value.lock();
+            if *current_value != *expected {
+                return None;
+            }
+
+            if self.change_detection.is_unchanged(&current_value, &value) {
+                return Some(Result::Unchanged);
+            }
+
+            if let Err(error) = self.validate(&value) {
+                return Some(Result::Rejected(error));
+            }
+
+            self.swap(&mut current_value, value.clone())
+        };
+
+        Some(self.notify(version, value, Some(old)).await)
+    }
+}
+
+impl<T: Clone + Send> Observable<T> {
+    // The comparator returns true if two values count as equal, e.g. to ignore timestamps
+    pub fn with_comparator(
+        value: T,
+        event_name: impl Into<String>,
+        comparator: impl Fn(&T, &T) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self::with_change_detection(
             value,
+            event_name,
+            ChangeDetection::EqualBy(Box::new(comparator)),
+        )
+    }
+
+    // For values without PartialEq, or to dispatch on every set
+    pub fn with_change_detection(
+        value: T,
+        event_name: impl Into<String>,
+        change_detection: ChangeDetection<T>,
+    ) -> Self {
+        let event_name = event_name.into();
+
+        Self {
+            value: Mutex::new(value),
+            change_detection,
+            validator: RwLock::new(None),
+            history: Mutex::new(None),
+            version: AtomicU64::new(0),
+            expiry: AtomicU64::new(0),
+            init_pending: AtomicBool::new(false),
+            init_notify: Notify::new(),
+            change_notify: Notify::new(),
+            notify_order: NotifyOrder::new(0),
+            on_transition: Event::new(format!("{} (transition)", event_name)),
             on_change: Event::new(event_name),
         }
     }
 
     pub fn get(&self) -> T {
-        self.value.clone()
+        self.value.lock().clone()
+    }
+
+    // Borrows the value instead of cloning it. The value stays locked while the closure runs,
+    // so the closure must not use the observable.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.value.lock())
+    }
+
+    // Drops the events along with their subscribers
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    // Increases with every change, starting at 0
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    pub fn get_versioned(&self) -> (u64, T) {
+        let value = self.value.lock();
+        (self.version(), value.clone())
+    }
+
+    pub fn changed_since(&self, version: u64) -> bool {
+        self.version() > version
+    }
+
+    // Resolves once the init future of Observable::new_async completed and its value was set.
+    // Observables created through new are always ready.
+    pub async fn ready(&self) {
+        loop {
+            let notified = self.init_notify.notified();
+            if self.is_ready() {
+                return;
+            }
+
+            notified.await;
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        !self.init_pending.load(Ordering::Acquire)
+    }
+
+    pub async fn get_when_ready(&self) -> T {
+        self.ready().await;
+        self.get()
+    }
+
+    // Every new value has to pass the validator before it is stored
+    pub fn set_validator(
+        &self,
+        validator: impl Fn(&T) -> CoreResult<(), BoxedError> + Send + Sync + 'static,
+    ) {
+        *self.validator.write() = Some(Box::new(validator));
+    }
+
+    pub fn remove_validator(&self) -> bool {
+        self.validator.write().take().is_some()
+    }
+
+    pub fn has_validator(&self) -> bool {
+        self.validator.read().is_some()
+    }
+
+    // Keeps up to capacity previous values for undo. Enabling it again clears the history.
+    pub fn enable_history(&self, capacity: usize) {
+        *self.history.lock() = Some(History::new(capacity));
+    }
+
+    pub fn disable_history(&self) -> bool {
+        self.history.lock().take().is_some()
+    }
+
+    pub fn has_history(&self) -> bool {
+        self.history.lock().is_some()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.history
+            .lock()
+            .as_ref()
+            .is_some_and(|history| !history.undo.is_empty())
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history
+            .lock()
+            .as_ref()
+            .is_some_and(|history| !history.redo.is_empty())
     }
 
     //TODO: Docs about cancelation safety. value can be dropped without reaching a channel.
-    pub async fn set(&mut self, value: T) -> Result<T> {
-        if self.value == value {
-            return Result::Unchanged;
+    // Every change is dispatched once. Concurrent sets are applied one after another and dispatched
+    // in that order, so subscribers end on the current value. A subscriber that awaits another
+    // change of the same observable waits for its own dispatch to finish, so it must spawn it.
+    pub async fn set(&self, value: T) -> Result<T> {
+        self.set_at_version(value, None).await
+    }
+
+    // Like set, but leaves the value alone unless it is still at the given version
+    async fn set_at_version(&self, value: T, version: Option<u64>) -> Result<T> {
+        let (old, version) = {
+            let mut current_value = self.value.lock();
+            if version.is_some_and(|version| self.version() != version)
+                || self.change_detection.is_unchanged(&current_value, &value)
+            {
+                return Result::Unchanged;
+            }
+
+            if let Err(error) = self.validate(&value) {
+                return Result::Rejected(error);
+            }
+
+            self.swap(&mut current_value, value.clone())
+        };
+
+        self.notify(version, value, Some(old)).await
+    }
+
+    // Dispatches even if the value didn't change, to re-notify subscribers
+    pub async fn force_set(&self, value: T) -> Result<T> {
+        let (old, version) = {
+            let mut current_value = self.value.lock();
+            if let Err(error) = self.validate(&value) {
+                return Result::Rejected(error);
+            }
+
+            self.swap(&mut current_value, value.clone())
+        };
+
+        self.notify(version, value, Some(old)).await
+    }
+
+    // Hands back the previous value, e.g. to recycle its buffers, along with the outcome like set
+    // reports it. An unchanged or rejected value is handed back as is.
+    pub async fn replace(&self, value: T) -> (T, Result<T>) {
+        let (old, version) = {
+            let mut current_value = self.value.lock();
+            if self.change_detection.is_unchanged(&current_value, &value) {
+                return (value, Result::Unchanged);
+            }
+
+            if let Err(error) = self.validate(&value) {
+                return (value, Result::Rejected(error));
+            }
+
+            self.swap(&mut current_value, value.clone())
+        };
+
+        let transition_old = self.has_transition_subscribers().then(|| old.clone());
+        let result = self.notify(version, value, transition_old).await;
+
+        (old, result)
+    }
+
+    // Mutates the value in place and always dispatches once afterwards
+    pub async fn update(&self, update: impl FnOnce(&mut T)) -> Result<T> {
+        let (value, old, version) = {
+            let mut current_value = self.value.lock();
+            let old =
+                (self.has_transition_subscribers() || self.has_validator() || self.has_history())
+                    .then(|| current_value.clone());
+            update(&mut current_value);
+
+            if let Err(error) = self.validate(&current_value) {
+                if let Some(old) = old {
+                    *current_value = old;
+                }
+
+                return Result::Rejected(error);
+            }
+
+            if let Some(old) = &old {
+                self.record_history(old);
+            }
+            let version = self.bump_version();
+
+            (current_value.clone(), old, version)
+        };
+
+        self.notify(version, value, old).await
+    }
+
+    // Like update, but compares against a copy of the previous value and skips unchanged values
+    pub async fn update_if_changed(&self, update: impl FnOnce(&mut T)) -> Result<T> {
+        let (value, old, version) = {
+            let mut current_value = self.value.lock();
+            let old = current_value.clone();
+            update(&mut current_value);
+            if self.change_detection.is_unchanged(&old, &current_value) {
+                return Result::Unchanged;
+            }
+
+            if let Err(error) = self.validate(&current_value) {
+                *current_value = old;
+                return Result::Rejected(error);
+            }
+
+            self.record_history(&old);
+            let version = self.bump_version();
+
+            (current_value.clone(), old, version)
+        };
+
+        self.notify(version, value, Some(old)).await
+    }
+
+    // Applies all changes of the transaction to a working copy and dispatches once at the end if
+    // the committed value differs from the one the transaction started with
+    pub async fn transaction(&self, transaction: impl FnOnce(&mut Transaction<T>)) -> Result<T> {
+        let (value, old, version) = {
+            let mut current_value = self.value.lock();
+            let mut working_copy = Transaction {
+                value: current_value.clone(),
+            };
+            transaction(&mut working_copy);
+            if self
+                .change_detection
+                .is_unchanged(&current_value, &working_copy.value)
+            {
+                return Result::Unchanged;
+            }
+
+            if let Err(error) = self.validate(&working_copy.value) {
+                return Result::Rejected(error);
+            }
+
+            let (old, version) = self.swap(&mut current_value, working_copy.value.clone());
+            (working_copy.value, old, version)
+        };
+
+        self.notify(version, value, Some(old)).await
+    }
+
+    // Restores the previous value from the history. Undo and redo skip the validator, as every
+    // value in the history was accepted before.
+    pub async fn undo(&self) -> Result<T> {
+        let (value, old, version) = {
+            let mut current_value = self.value.lock();
+            let Some(value) = self
+                .history
+                .lock()
+                .as_mut()
+                .and_then(|history| history.undo(&current_value))
+            else {
+                return Result::Unchanged;
+            };
+
+            let version = self.bump_version();
+            (
+                value.clone(),
+                std::mem::replace(&mut *current_value, value),
+                version,
+            )
+        };
+
+        self.notify(version, value, Some(old)).await
+    }
+
+    pub async fn redo(&self) -> Result<T> {
+        let (value, old, version) = {
+            let mut current_value = self.value.lock();
+            let Some(value) = self
+                .history
+                .lock()
+                .as_mut()
+                .and_then(|history| history.redo(&current_value))
+            else {
+                return Result::Unchanged;
+            };
+
+            let version = self.bump_version();
+            (
+                value.clone(),
+                std::mem::replace(&mut *current_value, value),
+                version,
+            )
+        };
+
+        self.notify(version, value, Some(old)).await
+    }
+
+    // Computes the new value from the current one under the lock and hands it back along with the
+    // outcome like set reports it. An unchanged or rejected value leaves the current one in place,
+    // which is handed back instead.
+    async fn apply(&self, apply: impl FnOnce(&T) -> T) -> (T, Result<T>) {
+        let (value, old, version) = {
+            let mut current_value = self.value.lock();
+            let value = apply(&current_value);
+            if self.change_detection.is_unchanged(&current_value, &value) {
+                return (current_value.clone(), Result::Unchanged);
+            }
+
+            if let Err(error) = self.validate(&value) {
+                return (current_value.clone(), Result::Rejected(error));
+            }
+
+            let (old, version) = self.swap(&mut current_value, value.clone());
+            (value, old, version)
+        };
+
+        let result = self.notify(version, value.clone(), Some(old)).await;
+        (value, result)
+    }
+
+    // Swaps in the new value and records the old one in the history. Hands back the old value and
+    // the new version, which the notification has to wait its turn with.
+    fn swap(&self, current_value: &mut T, value: T) -> (T, u64) {
+        let old = std::mem::replace(current_value, value);
+        self.record_history(&old);
+        (old, self.bump_version())
+    }
+
+    fn bump_version(&self) -> u64 {
+        self.version.fetch_add(1, Ordering::Release) + 1
+    }
+
+    fn record_history(&self, old: &T) {
+        if let Some(history) = self.history.lock().as_mut() {
+            history.record(old.clone());
         }
+    }
+
+    fn validate(&self, value: &T) -> CoreResult<(), BoxedError> {
+        match self.validator.read().as_ref() {
+            Some(validator) => validator(value),
+            None => Ok(()),
+        }
+    }
+
+    fn has_transition_subscribers(&self) -> bool {
+        self.on_transition.subscriber_count() > 0
+    }
+
+    // Resolves right away if the current value already satisfies the predicate
+    pub async fn wait_for(&self, predicate: impl Fn(&T) -> bool) -> T {
+        loop {
+            // Registered before the check, so a change in between still wakes us up
+            let notified = self.change_notify.notified();
+            let value = self.get();
+            if predicate(&value) {
+                return value;
+            }
+
+            notified.await;
+        }
+    }
+
+    // Resolves with the value after the next change, without subscribing to on_change. The value
+    // may already have changed again by the time the waiter reads it.
+    pub async fn changed(&self) -> T {
+        self.change_notify.notified().await;
+        self.get()
+    }
+
+    // Runs once the changes before this version were dispatched, so subscribers see concurrent
+    // changes in the order they were made
+    async fn notify(&self, version: u64, value: T, old: Option<T>) -> Result<T> {
+        let _turn = self.notify_order.wait_for_turn(version).await;
+        self.change_notify.notify_waiters();
+
+        // The old value is only cloned around if someone listens for transitions
+        let transition = old
+            .filter(|_| self.has_transition_subscribers())
+            .map(|old| Change {
+                old,
+                new: value.clone(),
+            });
 
-        self.value = value.clone();
         let dispatch_result = self.on_change.dispatch(value).await;
+        if let Some(transition) = transition {
+            // Failures are reported through the error handling of the transition subscribers
+            let _ = self.on_transition.dispatch(transition).await;
+        }
+
         match dispatch_result {
             Ok(_) => Result::Changed(Ok(())),
             Err(errors) => Result::Changed(Err(errors)),
@@ -43,19 +692,673 @@ impl<T: Clone + Send + PartialEq> Observable<T> {
     }
 }
 
-impl<T: Clone + Send + PartialEq> AsRef<T> for Observable<T> {
-    fn as_ref(&self) -> &T {
-        &self.value
+impl<T: Clone + Send + Sync + 'static> Observable<T> {
+    // The receiver starts with the current value. The value stays locked while subscribing, so no
+    // change can slip in between. The subscription ends once the receiver is dropped.
+    pub fn subscribe_watch(
+        &self,
+        name: impl Into<String>,
+    ) -> CoreResult<(u64, watch::Receiver<T>), SubscribeError> {
+        let current_value = self.value.lock();
+        let (sender, receiver) = watch::channel(current_value.clone());
+
+        let id = self.on_change.subscribe_flow_closure(
+            name,
+            move |data| match sender.send(data) {
+                Ok(()) => Ok(HandlerFlow::Continue),
+                Err(_) => Ok(HandlerFlow::Unsubscribe),
+            },
+            false,
+            false,
+        )?;
+        drop(current_value);
+
+        Ok((id, receiver))
+    }
+}
+
+impl<T: Clone + Send + PartialEq + 'static> Observable<T> {
+    // get returns the placeholder until init completes. Init is spawned on the event's runtime
+    // right away and its value is set like any other, unless a value was set in the meantime.
+    pub fn new_async(
+        placeholder: T,
+        init: impl Future<Output = T> + Send + 'static,
+        event_name: impl Into<String>,
+    ) -> Arc<Self> {
+        let observable = Arc::new(Self::new(placeholder, event_name));
+        observable.init_pending.store(true, Ordering::Release);
+
+        let weak = Arc::downgrade(&observable);
+        observable.on_change.runtime().spawn(Box::pin(async move {
+            let value = init.await;
+            let Some(observable) = weak.upgrade() else {
+                return;
+            };
+
+            // new_async starts at version 0. Anything set since then is newer than the initial
+            // value. Failures are reported through the error handling of the subscribers.
+            let _ = observable.set_at_version(value, Some(0)).await;
+            observable.init_pending.store(false, Ordering::Release);
+            observable.init_notify.notify_waiters();
+        }));
+
+        observable
+    }
+}
+
+impl<T: Clone + Send + 'static> Observable<T> {
+    pub fn reader(self: &Arc<Self>) -> ObservableReader<T> {
+        ObservableReader::new(self.clone())
+    }
+
+    // Like on_change.subscribe_closure, but can also call the closure with the current value. The
+    // subscription happens under the value's lock, so no change is missed. Changes that arrive
+    // while the current value is delivered are queued and delivered after it, so the current value
+    // always comes first. A change that was stored but not yet dispatched may repeat the current
+    // value. The closure is never called with a lock held.
+    pub fn subscribe_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> CoreResult<(), BoxedError> + Send + Sync + 'static,
+        deliver_current: bool,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<u64, SubscribeError> {
+        let name = name.into();
+        let closure = Arc::new(closure);
+        let subscribed_closure = closure.clone();
+        // Some while the current value is delivered, holding the changes that arrived meanwhile
+        let backlog = Arc::new(Mutex::new(None));
+        let subscribed_backlog = backlog.clone();
+
+        let (id, current) = self.subscribed_with(|current_value| {
+            let current = deliver_current.then(|| current_value.clone());
+            if current.is_some() {
+                *backlog.lock() = Some(VecDeque::new());
+            }
+
+            let id = self.on_change.subscribe_closure(
+                name.clone(),
+                move |data| {
+                    if let Some(backlog) = subscribed_backlog.lock().as_mut() {
+                        backlog.push_back(data);
+                        return Ok(());
+                    }
+
+                    subscribed_closure(data)
+                },
+                log_on_error,
+                remove_on_error,
+            )?;
+
+            Ok((id, current))
+        })?;
+
+        let Some(mut data) = current else {
+            return Ok(id);
+        };
+
+        loop {
+            if let Err(err) = closure(data) {
+                if log_on_error {
+                    error!(
+                        "Failed to deliver a value of observable \"{}\" to subscriber \"{}\": {}.",
+                        self.on_change.name(),
+                        name,
+                        err
+                    );
+                }
+
+                if remove_on_error {
+                    self.on_change.unsubscribe(id);
+                    return Ok(id);
+                }
+            }
+
+            let mut backlog = backlog.lock();
+            match backlog.as_mut().and_then(VecDeque::pop_front) {
+                Some(next) => data = next,
+                None => {
+                    *backlog = None;
+                    return Ok(id);
+                }
+            }
+        }
+    }
+
+    // Like on_change.subscribe_channel, but can queue the current value first. Both happen under
+    // the value's lock, so the current value is always received before any change.
+    pub fn subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        deliver_current: bool,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<(u64, Receiver<T>), SubscribeError> {
+        let current_value = self.value.lock();
+        let (sender, receiver) = channel(buffer);
+        if deliver_current {
+            // The channel is still empty, so there is room for the current value
+            let _ = sender.try_send(current_value.clone());
+        }
+
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Channel(sender),
+        );
+        let id = self.on_change.subscribe(subscriber)?;
+
+        Ok((id, receiver))
+    }
+
+    // The value is read and the subscription created under the same lock, so no change between
+    // the two can be missed
+    pub fn get_and_subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<SubscribedValue<T>, SubscribeError> {
+        self.subscribed_with(|current_value| {
+            let (id, receiver) =
+                self.on_change
+                    .subscribe_channel(name, buffer, log_on_error, remove_on_error)?;
+
+            Ok((current_value.clone(), id, receiver))
+        })
+    }
+
+    // Runs subscribe with the current value while the value stays locked, so a change can't slip
+    // in between reading the value and subscribing. subscribe must not access this observable.
+    fn subscribed_with<R>(&self, subscribe: impl FnOnce(&T) -> R) -> R {
+        let current_value = self.value.lock();
+        subscribe(&current_value)
+    }
+
+    // Sets the value and reverts it to expired after the ttl, e.g. for transient statuses. The
+    // revert is skipped if the value changed again in the meantime, and setting the same value
+    // again restarts the ttl.
+    pub async fn set_expiring(self: &Arc<Self>, value: T, ttl: Duration, expired: T) -> Result<T> {
+        let (version, expiry, old) = {
+            let mut current_value = self.value.lock();
+            if self.change_detection.is_unchanged(&current_value, &value) {
+                let expiry = self.expiry.fetch_add(1, Ordering::AcqRel) + 1;
+                (self.version(), expiry, None)
+            } else {
+                // A rejected value leaves the pending revert of an earlier call in place
+                if let Err(error) = self.validate(&value) {
+                    return Result::Rejected(error);
+                }
+
+                let expiry = self.expiry.fetch_add(1, Ordering::AcqRel) + 1;
+                let (old, version) = self.swap(&mut current_value, value.clone());
+                (version, expiry, Some(old))
+            }
+        };
+
+        let target = Arc::downgrade(self);
+        let runtime = self.on_change.runtime().clone();
+        let task_runtime = runtime.clone();
+        runtime.spawn(Box::pin(async move {
+            task_runtime.sleep(ttl).await;
+            if let Some(target) = target.upgrade() {
+                target.expire(version, expiry, expired).await;
+            }
+        }));
+
+        match old {
+            Some(old) => self.notify(version, value, Some(old)).await,
+            None => Result::Unchanged,
+        }
+    }
+
+    async fn expire(&self, version: u64, expiry: u64, expired: T) {
+        let (old, version) = {
+            let mut current_value = self.value.lock();
+            if self.version() != version
+                || self.expiry.load(Ordering::Acquire) != expiry
+                || self.change_detection.is_unchanged(&current_value, &expired)
+                || self.validate(&expired).is_err()
+            {
+                return;
+            }
+
+            self.swap(&mut current_value, expired.clone())
+        };
+
+        // Failures are reported through the error handling of the subscribers
+        let _ = self.notify(version, expired, Some(old)).await;
+    }
+
+    // Single-writer access: only the writer can change the value, readers can be cloned
+    pub fn split(self) -> (ObservableWriter<T>, ObservableReader<T>) {
+        let observable = Arc::new(self);
+        (
+            ObservableWriter::new(observable.clone()),
+            ObservableReader::new(observable),
+        )
+    }
+
+    // Sets the value whenever the event dispatches, which notifies the observable's own
+    // subscribers. The event only holds a weak reference, so the subscription is pruned once the
+    // observable is dropped.
+    pub fn driven_by(self: &Arc<Self>, event: &Event<T>) -> CoreResult<u64, SubscribeError> {
+        self.follow(event, Some)
+    }
+
+    // Sets the value to whatever forward returns for the source's payloads
+    pub(crate) fn follow<S: Clone + Send + 'static>(
+        self: &Arc<Self>,
+        source: &Event<S>,
+        forward: impl Fn(S) -> Option<T> + Send + Sync + 'static,
+    ) -> CoreResult<u64, SubscribeError> {
+        let target = Arc::downgrade(self);
+        let subscriber = Subscriber::new(
+            self.on_change.name(),
+            false,
+            true,
+            Callback::AsyncClosure(Box::new(move |data| {
+                let forwarded = forward(data);
+                let target = target.clone();
+
+                Box::pin(async move {
+                    if let (Some(value), Some(target)) = (forwarded, target.upgrade()) {
+                        // Failures are reported through the error handling of the own subscribers
+                        let _ = target.set(value).await;
+                    }
+
+                    Ok(())
+                })
+            })),
+        )
+        .with_owner(self);
+
+        source.subscribe(subscriber)
+    }
+
+    // Only dispatches when the extracted key changes, regardless of the rest of the value
+    pub fn distinct_until_changed_by<K: PartialEq>(
+        &self,
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> CoreResult<DerivedObservable<T>, SubscribeError> {
+        self.subscribed_with(|current_value| {
+            let distinct = DerivedObservable::with_change_detection(
+                current_value.clone(),
+                format!("{} (distinct)", self.on_change.name()),
+                ChangeDetection::EqualBy(Box::new(move |current, value| {
+                    key(current) == key(value)
+                })),
+            );
+            distinct.follow(&self.on_change, Some)?;
+
+            Ok(distinct)
+        })
+    }
+
+    // Binds a single field for reading and writing. Subscribers of the lens only hear about changes
+    // of that field.
+    pub fn lens<F: Clone + Send + PartialEq + 'static>(
+        self: &Arc<Self>,
+        get: impl Fn(&T) -> &F + Send + Sync + 'static,
+        set: impl Fn(&mut T, F) + Send + Sync + 'static,
+    ) -> CoreResult<ObservableLens<T, F>, SubscribeError> {
+        let projection = self.map(move |value| get(value).clone())?;
+        Ok(ObservableLens::new(self.clone(), projection, set))
+    }
+
+    // Projects the value, e.g. a single field of a struct. The projection only dispatches when
+    // the projected value changes. The first projection runs while the value is locked, so map
+    // must not access this observable.
+    pub fn map<U: Clone + Send + PartialEq + 'static>(
+        &self,
+        map: impl Fn(&T) -> U + Send + Sync + 'static,
+    ) -> CoreResult<DerivedObservable<U>, SubscribeError> {
+        self.subscribed_with(|current_value| {
+            let mapped = DerivedObservable::new(
+                map(current_value),
+                format!("{} (mapped)", self.on_change.name()),
+            );
+            mapped.follow(&self.on_change, move |data| Some(map(&data)))?;
+
+            Ok(mapped)
+        })
     }
 }
 
+impl<T: Clone + Send + PartialEq + 'static> Observable<T> {
+    // Stream of all future values, backed by a channel with the event's default buffer. The
+    // subscription is removed on the first dispatch after the stream was dropped.
+    #[cfg(feature = "futures")]
+    pub fn changes(&self) -> CoreResult<impl Stream<Item = T> + use<T>, SubscribeError> {
+        let (_, receiver) = self.on_change.subscribe_channel(
+            format!("{} (changes)", self.on_change.name()),
+            self.on_change.subscribe_defaults().channel_buffer,
+            false,
+            true,
+        )?;
+
+        Ok(stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|data| (data, receiver))
+        }))
+    }
+
+    // Only follows values passing the predicate and keeps the last accepted one otherwise.
+    // Starts with the fallback if the current value does not pass. The current value is checked
+    // while it is locked, so predicate must not access this observable.
+    pub fn filtered(
+        &self,
+        fallback: T,
+        predicate: impl Fn(&T) -> bool + Send + Sync + 'static,
+    ) -> CoreResult<DerivedObservable<T>, SubscribeError> {
+        self.subscribed_with(|current_value| {
+            let initial_value = if predicate(current_value) {
+                current_value.clone()
+            } else {
+                fallback
+            };
+
+            let filtered = DerivedObservable::new(
+                initial_value,
+                format!("{} (filtered)", self.on_change.name()),
+            );
+            filtered.follow(&self.on_change, move |data| {
+                predicate(&data).then_some(data)
+            })?;
+
+            Ok(filtered)
+        })
+    }
+
+    // Settles to the last value once the source has been quiet for the given period
+    pub fn debounced(
+        &self,
+        quiet_period: Duration,
+    ) -> CoreResult<DerivedObservable<T>, SubscribeError> {
+        let name = format!("{} (debounced)", self.on_change.name());
+        self.subscribed_with(|current_value| {
+            let debounced = DerivedObservable::new(current_value.clone(), name.clone());
+            debounced.follow_relay(
+                &self.on_change,
+                DebouncedEvent::new(name, quiet_period),
+                DebouncedEvent::dispatch,
+            )?;
+
+            Ok(debounced)
+        })
+    }
+
+    // Emits at most once per interval. Intermediate values are conflated into the latest one.
+    pub fn throttled(
+        &self,
+        interval: Duration,
+    ) -> CoreResult<DerivedObservable<T>, SubscribeError> {
+        let name = format!("{} (throttled)", self.on_change.name());
+        self.subscribed_with(|current_value| {
+            let throttled = DerivedObservable::new(current_value.clone(), name.clone());
+            throttled.follow_relay(
+                &self.on_change,
+                ThrottledEvent::new(name, interval, ThrottleMode::Conflate),
+                ThrottledEvent::dispatch,
+            )?;
+
+            Ok(throttled)
+        })
+    }
+
+    // Snapshots the value once per interval and only dispatches if it changed since the last sample
+    pub fn sampled(&self, interval: Duration) -> CoreResult<DerivedObservable<T>, SubscribeError> {
+        self.subscribed_with(|current_value| {
+            let sampled = DerivedObservable::new(
+                current_value.clone(),
+                format!("{} (sampled)", self.on_change.name()),
+            );
+            sampled.sample(self, current_value.clone(), interval)?;
+
+            Ok(sampled)
+        })
+    }
+
+    // Recomputes the value whenever either source changes. The latest source values are cached,
+    // so compute never has to lock the sources themselves.
+    pub fn computed<A, B>(
+        sources: (&Observable<A>, &Observable<B>),
+        compute: impl Fn(&A, &B) -> T + Send + Sync + 'static,
+    ) -> CoreResult<DerivedObservable<T>, SubscribeError>
+    where
+        A: Clone + Send + PartialEq + 'static,
+        B: Clone + Send + PartialEq + 'static,
+    {
+        let (a, b) = sources;
+        let compute = Arc::new(compute);
+        let subscribe = |a_value: &A, b_value: &B| {
+            let value = compute(a_value, b_value);
+            let inputs = Arc::new(Mutex::new((a_value.clone(), b_value.clone())));
+            let computed = DerivedObservable::new(
+                value,
+                format!("{} + {} (computed)", a.on_change.name(), b.on_change.name()),
+            );
+
+            let a_inputs = inputs.clone();
+            let a_compute = compute.clone();
+            computed.follow(&a.on_change, move |data| {
+                let mut inputs = a_inputs.lock();
+                inputs.0 = data;
+                Some(a_compute(&inputs.0, &inputs.1))
+            })?;
+
+            let b_compute = compute.clone();
+            computed.follow(&b.on_change, move |data| {
+                let mut inputs = inputs.lock();
+                inputs.1 = data;
+                Some(b_compute(&inputs.0, &inputs.1))
+            })?;
+
+            Ok(computed)
+        };
+
+        // Both sources stay locked until the computed observable follows them. They are locked in
+        // address order, so two computations over the same sources can't deadlock each other.
+        let a_address = (a as *const Observable<A>).addr();
+        let b_address = (b as *const Observable<B>).addr();
+        if a_address == b_address {
+            a.subscribed_with(|a_value| {
+                let b_value = (a_value as &dyn Any)
+                    .downcast_ref::<B>()
+                    .expect("Sources at the same address are the same observable");
+                subscribe(a_value, b_value)
+            })
+        } else if a_address < b_address {
+            a.subscribed_with(|a_value| b.subscribed_with(|b_value| subscribe(a_value, b_value)))
+        } else {
+            b.subscribed_with(|b_value| a.subscribed_with(|a_value| subscribe(a_value, b_value)))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Observable<T>
+where
+    T: Clone + Send + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    // Starts with the stored value, or the default if nothing was saved yet, and saves every
+    // accepted change once the value has been quiet for the persistence's debounce period
+    pub async fn new_persisted(
+        default: T,
+        event_name: impl Into<String>,
+        persistence: Persistence,
+    ) -> CoreResult<Self, PersistenceError> {
+        let value = persistence.load().await?.unwrap_or(default);
+        let observable = Self::new(value, event_name);
+        persistence.attach(&observable.on_change)?;
+
+        Ok(observable)
+    }
+}
+
+impl<T: Clone + Send + Debug> Debug for Observable<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("on_change", &self.on_change)
+            .field("on_transition", &self.on_transition)
+            .field("value", &self.value)
+            .field("change_detection", &self.change_detection)
+            .field("validator", &self.has_validator())
+            .field("history", &self.history)
+            .field("version", &self.version())
+            .field("ready", &self.is_ready())
+            .finish()
+    }
+}
+
+impl<T: Clone + Send> Observable<Option<T>> {
+    pub fn is_some(&self) -> bool {
+        self.value.lock().is_some()
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.value.lock().is_none()
+    }
+
+    pub async fn set_some(&self, value: T) -> Result<Option<T>> {
+        self.set(Some(value)).await
+    }
+
+    // Hands back the value and dispatches None. Returns None without dispatching if there was no
+    // value or the validator rejected None.
+    pub async fn take(&self) -> Option<T> {
+        let (old, version) = {
+            let mut current_value = self.value.lock();
+            if current_value.is_none() || self.validate(&None).is_err() {
+                return None;
+            }
+
+            self.swap(&mut current_value, None)
+        };
+
+        let transition_old = self.has_transition_subscribers().then(|| old.clone());
+        // Failures are reported through the error handling of the subscribers
+        let _ = self.notify(version, None, transition_old).await;
+
+        old
+    }
+
+    // Resolves right away if there already is a value
+    pub async fn when_some(&self) -> T {
+        loop {
+            let notified = self.change_notify.notified();
+            if let Some(value) = self.get() {
+                return value;
+            }
+
+            notified.await;
+        }
+    }
+}
+
+// Arithmetic that reads and writes under the same lock, so concurrent updates are never lost.
+// Every method hands back the value after the call along with the outcome like set reports it.
+macro_rules! impl_float_observable {
+    ($($float:ty),*) => {$(
+        impl Observable<$float> {
+            pub async fn add(&self, rhs: $float) -> ($float, Result<$float>) {
+                self.apply(|value| value + rhs).await
+            }
+
+            pub async fn sub(&self, rhs: $float) -> ($float, Result<$float>) {
+                self.apply(|value| value - rhs).await
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_integer_observable {
+    ($($integer:ty),*) => {$(
+        impl Observable<$integer> {
+            // Wraps around on overflow instead of panicking while the value is locked
+            pub async fn add(&self, rhs: $integer) -> ($integer, Result<$integer>) {
+                self.apply(|value| value.wrapping_add(rhs)).await
+            }
+
+            // Wraps around on overflow instead of panicking while the value is locked
+            pub async fn sub(&self, rhs: $integer) -> ($integer, Result<$integer>) {
+                self.apply(|value| value.wrapping_sub(rhs)).await
+            }
+
+            // Wraps around on overflow, like add
+            pub async fn incr(&self) -> ($integer, Result<$integer>) {
+                self.add(1).await
+            }
+
+            // Wraps around on overflow, like sub
+            pub async fn decr(&self) -> ($integer, Result<$integer>) {
+                self.sub(1).await
+            }
+
+            // Returns None and keeps the current value on overflow
+            pub async fn checked_add(&self, rhs: $integer) -> Option<($integer, Result<$integer>)> {
+                let mut overflowed = false;
+                let applied = self
+                    .apply(|value| {
+                        value.checked_add(rhs).unwrap_or_else(|| {
+                            overflowed = true;
+                            *value
+                        })
+                    })
+                    .await;
+
+                (!overflowed).then_some(applied)
+            }
+
+            // Returns None and keeps the current value on overflow
+            pub async fn checked_sub(&self, rhs: $integer) -> Option<($integer, Result<$integer>)> {
+                let mut overflowed = false;
+                let applied = self
+                    .apply(|value| {
+                        value.checked_sub(rhs).unwrap_or_else(|| {
+                            overflowed = true;
+                            *value
+                        })
+                    })
+                    .await;
+
+                (!overflowed).then_some(applied)
+            }
+
+            pub async fn saturating_add(&self, rhs: $integer) -> ($integer, Result<$integer>) {
+                self.apply(|value| value.saturating_add(rhs)).await
+            }
+
+            pub async fn saturating_sub(&self, rhs: $integer) -> ($integer, Result<$integer>) {
+                self.apply(|value| value.saturating_sub(rhs)).await
+            }
+        }
+    )*};
+}
+
+impl_float_observable!(f32, f64);
+impl_integer_observable!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+// The events are named after the value's type
+impl<T: Clone + Send + PartialEq + Default> Default for Observable<T> {
+    fn default() -> Self {
+        Self::new(T::default(), type_name::<T>())
+    }
+}
+
+// The &str is copied out of the lock, it doesn't borrow from the observable
 impl AsRef<str> for Observable<&str> {
     fn as_ref(&self) -> &str {
-        self.value
+        *self.value.lock()
     }
 }
 
-impl<T: Clone + Send + PartialEq> AsRef<Event<T>> for Observable<T> {
+impl<T: Clone + Send> AsRef<Event<T>> for Observable<T> {
     fn as_ref(&self) -> &Event<T> {
         &self.on_change
     }
@@ -63,13 +1366,16 @@ impl<T: Clone + Send + PartialEq> AsRef<Event<T>> for Observable<T> {
 
 impl<T: Clone + Send + PartialEq> PartialEq for Observable<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+        // Holding both locks at once deadlocks when two comparisons run in opposite directions,
+        // or when an observable is compared with itself
+        let other_value = other.get();
+        *self.value.lock() == other_value
     }
 }
 
 impl<T: Clone + Send + PartialEq> PartialEq<T> for Observable<T> {
     fn eq(&self, other: &T) -> bool {
-        self.value == *other
+        *self.value.lock() == *other
     }
 }
 
@@ -77,6 +1383,35 @@ impl<T: Clone + Send + PartialEq> Eq for Observable<T> {}
 
 impl<T: Clone + Send + PartialEq + Hash> Hash for Observable<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.value.hash(state);
+        self.value.lock().hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Clone + Send + Serialize> Serialize for Observable<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> CoreResult<S::Ok, S::Error> {
+        self.value.lock().serialize(serializer)
+    }
+}
+
+// Deserialized observables start without subscribers and their events are named after the
+// value's type. Use Observable::deserialize_named to pick the name.
+#[cfg(feature = "serde")]
+impl<'de, T: Clone + Send + PartialEq + Deserialize<'de>> Deserialize<'de> for Observable<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> CoreResult<Self, D::Error> {
+        Self::deserialize_named(deserializer, type_name::<T>())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Clone + Send + PartialEq> Observable<T> {
+    pub fn deserialize_named<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        event_name: impl Into<String>,
+    ) -> CoreResult<Self, D::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        T::deserialize(deserializer).map(|value| Self::new(value, event_name))
     }
 }
@@ -0,0 +1,157 @@
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::{Event, observable::Result};
+
+// Copy types that fit into 64 bits, so ObservableCell can keep them in an AtomicU64
+pub trait CellValue: Copy + Send + Sync + 'static {
+    fn into_cell_bits(self) -> u64;
+    fn from_cell_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_integer_cell_value {
+    ($($integer:ty),*) => {$(
+        impl CellValue for $integer {
+            fn into_cell_bits(self) -> u64 {
+                self as u64
+            }
+
+            fn from_cell_bits(bits: u64) -> Self {
+                bits as $integer
+            }
+        }
+    )*};
+}
+
+impl_integer_cell_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl CellValue for bool {
+    fn into_cell_bits(self) -> u64 {
+        self as u64
+    }
+
+    fn from_cell_bits(bits: u64) -> Self {
+        bits != 0
+    }
+}
+
+impl CellValue for f32 {
+    fn into_cell_bits(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    fn from_cell_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl CellValue for f64 {
+    fn into_cell_bits(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_cell_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+// Lock-free alternative to Observable for small Copy values. Values are compared by their bits,
+// so e.g. 0.0 and -0.0 count as a change while equal NaNs don't.
+#[derive(Debug)]
+pub struct ObservableCell<T: CellValue> {
+    pub on_change: Event<T>,
+
+    bits: AtomicU64,
+    _value: PhantomData<T>,
+}
+
+impl<T: CellValue> ObservableCell<T> {
+    pub fn new(value: T, event_name: impl Into<String>) -> Self {
+        Self {
+            on_change: Event::new(event_name),
+            bits: AtomicU64::new(value.into_cell_bits()),
+            _value: PhantomData,
+        }
+    }
+
+    pub fn get(&self) -> T {
+        T::from_cell_bits(self.bits.load(Ordering::Acquire))
+    }
+
+    pub async fn set(&self, value: T) -> Result<T> {
+        let bits = value.into_cell_bits();
+        if self.bits.swap(bits, Ordering::AcqRel) == bits {
+            return Result::Unchanged;
+        }
+
+        self.notify(value).await
+    }
+
+    // Applies update atomically, retrying if another write got in between, and returns the new value
+    pub async fn update(&self, update: impl Fn(T) -> T) -> T {
+        let mut value = self.get();
+        let old_bits = self
+            .bits
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |bits| {
+                value = update(T::from_cell_bits(bits));
+                Some(value.into_cell_bits())
+            })
+            .unwrap_or_else(|bits| bits);
+
+        if old_bits != value.into_cell_bits() {
+            // Failures are reported through the error handling of the subscribers
+            let _ = self.notify(value).await;
+        }
+
+        value
+    }
+
+    async fn notify(&self, value: T) -> Result<T> {
+        match self.on_change.dispatch(value).await {
+            Ok(()) => Result::Changed(Ok(())),
+            Err(errors) => Result::Changed(Err(errors)),
+        }
+    }
+}
+
+impl<T: CellValue> AsRef<Event<T>> for ObservableCell<T> {
+    fn as_ref(&self) -> &Event<T> {
+        &self.on_change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[tokio::test]
+    async fn dispatch_changed_values() {
+        let cell = ObservableCell::new(1.5f64, EVENT_NAME);
+        let (_, mut receiver) = cell
+            .on_change
+            .subscribe_channel(SUBSCRIBER_NAME, 4, false, false)
+            .unwrap();
+
+        cell.set(1.5).await;
+        cell.set(-2.0).await;
+        assert_eq!(cell.update(|value| value * 2.0).await, -4.0);
+        assert_eq!(cell.get(), -4.0);
+
+        assert_eq!(receiver.recv().await, Some(-2.0));
+        assert_eq!(receiver.recv().await, Some(-4.0));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn round_trip_bits() {
+        assert_eq!(i8::from_cell_bits((-3i8).into_cell_bits()), -3);
+        assert!(bool::from_cell_bits(true.into_cell_bits()));
+        assert_eq!(f32::from_cell_bits(0.25f32.into_cell_bits()), 0.25);
+    }
+}
@@ -0,0 +1,107 @@
+use core::result::Result as CoreResult;
+use std::{
+    any::type_name,
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+use lum_boxtypes::BoxedError;
+use lum_libs::tokio::sync::mpsc::Receiver;
+
+use crate::{
+    DerivedObservable, Observable, Subscriber,
+    event::SubscribeError,
+    observable::{Change, Result},
+};
+
+pub type LensSetter<S, F> = dyn Fn(&mut S, F) + Send + Sync;
+
+// Read/write projection of a single field. Reads and subscriptions go through a derived
+// observable of the field, which only dispatches when the field itself changes. Writes update
+// the source in place.
+pub struct ObservableLens<S: Clone + Send, F: Clone + Send + PartialEq> {
+    source: Arc<Observable<S>>,
+    projection: DerivedObservable<F>,
+    setter: Box<LensSetter<S, F>>,
+}
+
+impl<S: Clone + Send + 'static, F: Clone + Send + PartialEq + 'static> ObservableLens<S, F> {
+    pub(crate) fn new(
+        source: Arc<Observable<S>>,
+        projection: DerivedObservable<F>,
+        setter: impl Fn(&mut S, F) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            source,
+            projection,
+            setter: Box::new(setter),
+        }
+    }
+
+    pub fn get(&self) -> F {
+        self.projection.get()
+    }
+
+    pub fn subscribe(&self, subscriber: Subscriber<F>) -> CoreResult<u64, SubscribeError> {
+        self.projection.subscribe(subscriber)
+    }
+
+    pub fn subscribe_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(F) -> CoreResult<(), BoxedError> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<u64, SubscribeError> {
+        self.projection
+            .subscribe_closure(name, closure, log_on_error, remove_on_error)
+    }
+
+    pub fn subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<(u64, Receiver<F>), SubscribeError> {
+        self.projection
+            .subscribe_channel(name, buffer, log_on_error, remove_on_error)
+    }
+
+    pub fn unsubscribe(&self, id: u64) -> bool {
+        self.projection.unsubscribe(id)
+    }
+
+    pub fn subscribe_transition(
+        &self,
+        subscriber: Subscriber<Change<F>>,
+    ) -> CoreResult<u64, SubscribeError> {
+        self.projection.subscribe_transition(subscriber)
+    }
+
+    pub fn unsubscribe_transition(&self, id: u64) -> bool {
+        self.projection.unsubscribe_transition(id)
+    }
+
+    // Only dispatches on the source if the source counts the write as a change
+    pub async fn set(&self, value: F) -> Result<S> {
+        self.source
+            .update_if_changed(|source| (self.setter)(source, value))
+            .await
+    }
+}
+
+impl<S: Clone + Send, F: Clone + Send + PartialEq + Debug> Debug for ObservableLens<S, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("source", &self.source.on_change.name())
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl<S: Clone + Send, F: Clone + Send + PartialEq> PartialEq<F> for ObservableLens<S, F> {
+    fn eq(&self, other: &F) -> bool {
+        self.projection == *other
+    }
+}
@@ -0,0 +1,221 @@
+use core::result::Result as CoreResult;
+use std::{collections::HashMap, hash::Hash};
+
+use lum_boxtypes::BoxedError;
+use lum_libs::{parking_lot::Mutex, tokio::sync::mpsc::Receiver};
+
+use crate::{
+    Event, Subscriber, SubscriberBuilder, event::SubscribeError, subscriber::DispatchFailure,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MapChange<K, V> {
+    Inserted(K, V),
+    Removed(K),
+    // Key, old value, new value
+    Updated(K, V, V),
+}
+
+impl<K, V> MapChange<K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            MapChange::Inserted(key, _)
+            | MapChange::Removed(key)
+            | MapChange::Updated(key, _, _) => key,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Result<K, V> {
+    Unchanged,
+    Changed(CoreResult<(), Vec<DispatchFailure<MapChange<K, V>>>>),
+}
+
+// Like Observable, the map is never locked during a dispatch
+#[derive(Debug)]
+pub struct ObservableHashMap<K, V>
+where
+    K: Clone + Send + Eq + Hash,
+    V: Clone + Send + PartialEq,
+{
+    pub on_change: Event<MapChange<K, V>>,
+
+    values: Mutex<HashMap<K, V>>,
+}
+
+impl<K, V> ObservableHashMap<K, V>
+where
+    K: Clone + Send + Eq + Hash,
+    V: Clone + Send + PartialEq,
+{
+    pub fn new(event_name: impl Into<String>) -> Self {
+        Self::from_map(HashMap::new(), event_name)
+    }
+
+    pub fn from_map(values: HashMap<K, V>, event_name: impl Into<String>) -> Self {
+        Self {
+            on_change: Event::new(event_name),
+            values: Mutex::new(values),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.values.lock().get(key).cloned()
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.values.lock().contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.lock().is_empty()
+    }
+
+    pub fn snapshot(&self) -> HashMap<K, V> {
+        self.values.lock().clone()
+    }
+
+    pub async fn insert(&self, key: K, value: V) -> Result<K, V> {
+        let change = {
+            let mut values = self.values.lock();
+            match values.insert(key.clone(), value.clone()) {
+                Some(old) if old == value => return Result::Unchanged,
+                Some(old) => MapChange::Updated(key, old, value),
+                None => MapChange::Inserted(key, value),
+            }
+        };
+
+        self.notify(change).await
+    }
+
+    pub async fn remove(&self, key: &K) -> Result<K, V> {
+        if self.values.lock().remove(key).is_none() {
+            return Result::Unchanged;
+        }
+
+        self.notify(MapChange::Removed(key.clone())).await
+    }
+
+    async fn notify(&self, change: MapChange<K, V>) -> Result<K, V> {
+        match self.on_change.dispatch(change).await {
+            Ok(()) => Result::Changed(Ok(())),
+            Err(errors) => Result::Changed(Err(errors)),
+        }
+    }
+}
+
+impl<K, V> ObservableHashMap<K, V>
+where
+    K: Clone + Send + Sync + Eq + Hash + 'static,
+    V: Clone + Send + PartialEq + 'static,
+{
+    // Only receives changes of the given key
+    pub fn subscribe_key(
+        &self,
+        name: impl Into<String>,
+        key: K,
+        closure: impl Fn(MapChange<K, V>) -> CoreResult<(), BoxedError> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<u64, SubscribeError> {
+        let subscriber =
+            Self::key_subscriber(name, key, log_on_error, remove_on_error).closure(closure);
+
+        self.on_change.subscribe(subscriber)
+    }
+
+    pub fn subscribe_key_channel(
+        &self,
+        name: impl Into<String>,
+        key: K,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> CoreResult<(u64, Receiver<MapChange<K, V>>), SubscribeError> {
+        let (subscriber, receiver) =
+            Self::key_subscriber(name, key, log_on_error, remove_on_error).channel(buffer);
+        let id = self.on_change.subscribe(subscriber)?;
+
+        Ok((id, receiver))
+    }
+
+    fn key_subscriber(
+        name: impl Into<String>,
+        key: K,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> SubscriberBuilder<MapChange<K, V>> {
+        Subscriber::builder(name)
+            .log_on_error(log_on_error)
+            .remove_on_error(remove_on_error)
+            .filter(move |change: &MapChange<K, V>| *change.key() == key)
+    }
+}
+
+impl<K, V> AsRef<Event<MapChange<K, V>>> for ObservableHashMap<K, V>
+where
+    K: Clone + Send + Eq + Hash,
+    V: Clone + Send + PartialEq,
+{
+    fn as_ref(&self) -> &Event<MapChange<K, V>> {
+        &self.on_change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[tokio::test]
+    async fn dispatch_keyed_changes() {
+        let map = ObservableHashMap::<&str, u8>::new(EVENT_NAME);
+        let (_, mut receiver) = map
+            .on_change
+            .subscribe_channel(SUBSCRIBER_NAME, 4, false, false)
+            .unwrap();
+
+        map.insert("volume", 1).await;
+        map.insert("volume", 1).await;
+        map.insert("volume", 2).await;
+        map.remove(&"volume").await;
+        map.remove(&"volume").await;
+
+        assert_eq!(
+            receiver.recv().await,
+            Some(MapChange::Inserted("volume", 1))
+        );
+        assert_eq!(
+            receiver.recv().await,
+            Some(MapChange::Updated("volume", 1, 2))
+        );
+        assert_eq!(receiver.recv().await, Some(MapChange::Removed("volume")));
+        assert!(receiver.try_recv().is_err());
+        assert!(map.is_empty());
+    }
+
+    #[tokio::test]
+    async fn only_deliver_subscribed_key() {
+        let map = ObservableHashMap::<&str, u8>::new(EVENT_NAME);
+        let (_, mut receiver) = map
+            .subscribe_key_channel(SUBSCRIBER_NAME, "volume", 4, false, false)
+            .unwrap();
+
+        map.insert("brightness", 1).await;
+        map.insert("volume", 2).await;
+
+        assert_eq!(
+            receiver.recv().await,
+            Some(MapChange::Inserted("volume", 2))
+        );
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use lum_boxtypes::BoxedError;
+use lum_libs::tokio::sync::mpsc::Receiver;
+
+use crate::{Observable, Subscriber, event::SubscribeError};
+
+// Read access to a shared Observable. Holders can read and subscribe, but neither set the value
+// nor dispatch on its events.
+#[derive(Debug, Clone)]
+pub struct ObservableReader<T: Clone + Send> {
+    observable: Arc<Observable<T>>,
+}
+
+impl<T: Clone + Send> ObservableReader<T> {
+    pub(crate) fn new(observable: Arc<Observable<T>>) -> Self {
+        Self { observable }
+    }
+
+    pub fn name(&self) -> &str {
+        self.observable.on_change.name()
+    }
+
+    pub fn get(&self) -> T {
+        self.observable.get()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.observable.version()
+    }
+
+    // Resolves with the value after the next change
+    pub async fn changed(&self) -> T {
+        self.observable.changed().await
+    }
+
+    pub fn subscribe(&self, subscriber: Subscriber<T>) -> Result<u64, SubscribeError>
+    where
+        T: 'static,
+    {
+        self.observable.on_change.subscribe(subscriber)
+    }
+
+    pub fn subscribe_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), BoxedError> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError>
+    where
+        T: 'static,
+    {
+        self.observable
+            .on_change
+            .subscribe_closure(name, closure, log_on_error, remove_on_error)
+    }
+
+    pub fn subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        buffer: usize,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<(u64, Receiver<T>), SubscribeError>
+    where
+        T: 'static,
+    {
+        self.observable
+            .on_change
+            .subscribe_channel(name, buffer, log_on_error, remove_on_error)
+    }
+
+    pub fn unsubscribe(&self, id: u64) -> bool {
+        self.observable.on_change.unsubscribe(id)
+    }
+}
+
+impl<T: Clone + Send + PartialEq> PartialEq<T> for ObservableReader<T> {
+    fn eq(&self, other: &T) -> bool {
+        *self.observable == *other
+    }
+}
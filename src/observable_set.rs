@@ -0,0 +1,114 @@
+use core::result::Result as CoreResult;
+use std::{collections::HashSet, hash::Hash};
+
+use lum_libs::parking_lot::Mutex;
+
+use crate::{Event, subscriber::DispatchFailure};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SetChange<T> {
+    Added(T),
+    Removed(T),
+}
+
+#[derive(Debug)]
+pub enum Result<T> {
+    Unchanged,
+    Changed(CoreResult<(), Vec<DispatchFailure<SetChange<T>>>>),
+}
+
+// Like Observable, the set is never locked during a dispatch
+#[derive(Debug)]
+pub struct ObservableSet<T: Clone + Send + Eq + Hash> {
+    pub on_change: Event<SetChange<T>>,
+
+    values: Mutex<HashSet<T>>,
+}
+
+impl<T: Clone + Send + Eq + Hash> ObservableSet<T> {
+    pub fn new(event_name: impl Into<String>) -> Self {
+        Self::from_set(HashSet::new(), event_name)
+    }
+
+    pub fn from_set(values: HashSet<T>, event_name: impl Into<String>) -> Self {
+        Self {
+            on_change: Event::new(event_name),
+            values: Mutex::new(values),
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.values.lock().contains(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.lock().is_empty()
+    }
+
+    pub fn snapshot(&self) -> HashSet<T> {
+        self.values.lock().clone()
+    }
+
+    pub async fn insert(&self, value: T) -> Result<T> {
+        if !self.values.lock().insert(value.clone()) {
+            return Result::Unchanged;
+        }
+
+        self.notify(SetChange::Added(value)).await
+    }
+
+    pub async fn remove(&self, value: &T) -> Result<T> {
+        let Some(removed) = self.values.lock().take(value) else {
+            return Result::Unchanged;
+        };
+
+        self.notify(SetChange::Removed(removed)).await
+    }
+
+    async fn notify(&self, change: SetChange<T>) -> Result<T> {
+        match self.on_change.dispatch(change).await {
+            Ok(()) => Result::Changed(Ok(())),
+            Err(errors) => Result::Changed(Err(errors)),
+        }
+    }
+}
+
+impl<T: Clone + Send + Eq + Hash> AsRef<Event<SetChange<T>>> for ObservableSet<T> {
+    fn as_ref(&self) -> &Event<SetChange<T>> {
+        &self.on_change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[tokio::test]
+    async fn dispatch_membership_deltas() {
+        let set = ObservableSet::<&str>::new(EVENT_NAME);
+        let (_, mut receiver) = set
+            .on_change
+            .subscribe_channel(SUBSCRIBER_NAME, 4, false, false)
+            .unwrap();
+
+        set.insert("peer").await;
+        set.insert("peer").await;
+        assert!(set.contains(&"peer"));
+
+        set.remove(&"peer").await;
+        set.remove(&"peer").await;
+
+        assert_eq!(receiver.recv().await, Some(SetChange::Added("peer")));
+        assert_eq!(receiver.recv().await, Some(SetChange::Removed("peer")));
+        assert!(receiver.try_recv().is_err());
+        assert!(set.is_empty());
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::{
+    Observable, ObservableReader,
+    observable::{Result, Transaction},
+};
+
+// The only handle that can change an Observable created through Observable::split. It is not
+// Clone, so there is exactly one writer, while readers can be cloned freely.
+#[derive(Debug)]
+pub struct ObservableWriter<T: Clone + Send> {
+    observable: Arc<Observable<T>>,
+}
+
+impl<T: Clone + Send + 'static> ObservableWriter<T> {
+    pub(crate) fn new(observable: Arc<Observable<T>>) -> Self {
+        Self { observable }
+    }
+
+    pub fn get(&self) -> T {
+        self.observable.get()
+    }
+
+    pub fn reader(&self) -> ObservableReader<T> {
+        self.observable.reader()
+    }
+
+    pub async fn set(&self, value: T) -> Result<T> {
+        self.observable.set(value).await
+    }
+
+    pub async fn force_set(&self, value: T) -> Result<T> {
+        self.observable.force_set(value).await
+    }
+
+    pub async fn replace(&self, value: T) -> (T, Result<T>) {
+        self.observable.replace(value).await
+    }
+
+    pub async fn update(&self, update: impl FnOnce(&mut T)) -> Result<T> {
+        self.observable.update(update).await
+    }
+
+    pub async fn update_if_changed(&self, update: impl FnOnce(&mut T)) -> Result<T> {
+        self.observable.update_if_changed(update).await
+    }
+
+    pub async fn transaction(&self, transaction: impl FnOnce(&mut Transaction<T>)) -> Result<T> {
+        self.observable.transaction(transaction).await
+    }
+}
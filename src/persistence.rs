@@ -0,0 +1,281 @@
+use std::{io::ErrorKind, path::PathBuf, sync::Arc, time::Duration};
+
+use lum_boxtypes::{BoxedError, LifetimedPinnedBoxedFuture};
+use lum_libs::{
+    serde::{Serialize, de::DeserializeOwned},
+    tokio::{fs, sync::mpsc::unbounded_channel},
+};
+use lum_log::error;
+use thiserror::Error;
+
+use crate::{Event, debounced_event::run_debounce_loop, event::SubscribeError};
+
+pub trait PersistenceStore: Send + Sync + 'static {
+    // Returns None if nothing was saved under the key yet
+    fn load<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> LifetimedPinnedBoxedFuture<'a, Result<Option<Vec<u8>>, BoxedError>>;
+
+    fn save<'a>(
+        &'a self,
+        key: &'a str,
+        data: Vec<u8>,
+    ) -> LifetimedPinnedBoxedFuture<'a, Result<(), BoxedError>>;
+}
+
+// Stores every key as {key}.json inside the directory
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    directory: PathBuf,
+}
+
+impl FileStore {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", key))
+    }
+}
+
+impl PersistenceStore for FileStore {
+    fn load<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> LifetimedPinnedBoxedFuture<'a, Result<Option<Vec<u8>>, BoxedError>> {
+        Box::pin(async move {
+            match fs::read(self.path(key)).await {
+                Ok(data) => Ok(Some(data)),
+                Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+                Err(error) => Err(error.into()),
+            }
+        })
+    }
+
+    fn save<'a>(
+        &'a self,
+        key: &'a str,
+        data: Vec<u8>,
+    ) -> LifetimedPinnedBoxedFuture<'a, Result<(), BoxedError>> {
+        // Renaming replaces the file at once, so a crash mid-write can't leave it truncated
+        Box::pin(async move {
+            fs::create_dir_all(&self.directory).await?;
+            let path = self.path(key);
+            let temp_path = path.with_extension("json.tmp");
+            fs::write(&temp_path, data).await?;
+            fs::rename(&temp_path, &path).await?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum PersistenceError {
+    #[error("Failed to load \"{key}\": {source}")]
+    Load { key: String, source: BoxedError },
+
+    #[error("Failed to deserialize \"{key}\": {source}")]
+    Deserialize {
+        key: String,
+        source: serde_json::Error,
+    },
+
+    #[error("Failed to subscribe the persistence writer: {0}")]
+    Subscribe(#[from] SubscribeError),
+}
+
+#[derive(Clone)]
+pub struct Persistence {
+    pub store: Arc<dyn PersistenceStore>,
+    pub key: String,
+    // Changes are saved once the value has been quiet for this long
+    pub debounce: Duration,
+}
+
+impl Persistence {
+    pub fn new(store: impl PersistenceStore, key: impl Into<String>, debounce: Duration) -> Self {
+        Self {
+            store: Arc::new(store),
+            key: key.into(),
+            debounce,
+        }
+    }
+
+    pub(crate) async fn load<T: DeserializeOwned>(&self) -> Result<Option<T>, PersistenceError> {
+        let data = self
+            .store
+            .load(&self.key)
+            .await
+            .map_err(|source| PersistenceError::Load {
+                key: self.key.clone(),
+                source,
+            })?;
+
+        let Some(data) = data else {
+            return Ok(None);
+        };
+
+        serde_json::from_slice(&data)
+            .map(Some)
+            .map_err(|source| PersistenceError::Deserialize {
+                key: self.key.clone(),
+                source,
+            })
+    }
+
+    // Saves the source's payloads once they have been quiet for the debounce period. The save task
+    // owns the store, so a change that is still waiting is saved right away when the source is dropped.
+    pub(crate) fn attach<T: Clone + Send + Serialize + 'static>(
+        self,
+        source: &Event<T>,
+    ) -> Result<(), PersistenceError> {
+        let (queue_sender, queue_receiver) = unbounded_channel::<T>();
+        let runtime = source.runtime().clone();
+        let debounce = self.debounce;
+        runtime.spawn(Box::pin(run_debounce_loop(
+            runtime.clone(),
+            queue_receiver,
+            debounce,
+            move |data| {
+                let persistence = self.clone();
+                let encoded = serde_json::to_vec(&data);
+                async move {
+                    persistence.save(encoded).await;
+                    true
+                }
+            },
+        )));
+
+        source.subscribe_closure(
+            format!("{} (persistence)", source.name()),
+            move |data| {
+                queue_sender
+                    .send(data)
+                    .map_err(|err| err.to_string().into())
+            },
+            true,
+            false,
+        )?;
+
+        Ok(())
+    }
+
+    // Failed saves are logged, the next change tries again
+    async fn save(&self, encoded: serde_json::Result<Vec<u8>>) {
+        let result = match encoded {
+            Ok(encoded) => self.store.save(&self.key, encoded).await,
+            Err(err) => Err(err.into()),
+        };
+
+        if let Err(err) = result {
+            error!("Failed to save \"{}\": {}.", self.key, err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use lum_libs::{parking_lot::Mutex, tokio};
+
+    use super::*;
+    use crate::Observable;
+
+    const EVENT_NAME: &str = "test_event";
+    const KEY: &str = "settings";
+    const DEBOUNCE: Duration = Duration::from_millis(20);
+
+    #[derive(Default)]
+    struct MemoryStore {
+        entries: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    }
+
+    impl PersistenceStore for MemoryStore {
+        fn load<'a>(
+            &'a self,
+            key: &'a str,
+        ) -> LifetimedPinnedBoxedFuture<'a, Result<Option<Vec<u8>>, BoxedError>> {
+            Box::pin(async move { Ok(self.entries.lock().get(key).cloned()) })
+        }
+
+        fn save<'a>(
+            &'a self,
+            key: &'a str,
+            data: Vec<u8>,
+        ) -> LifetimedPinnedBoxedFuture<'a, Result<(), BoxedError>> {
+            Box::pin(async move {
+                self.entries.lock().insert(key.to_string(), data);
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn load_and_save_value() {
+        let entries = Arc::new(Mutex::new(HashMap::new()));
+        entries.lock().insert(KEY.to_string(), b"3".to_vec());
+        let store = MemoryStore {
+            entries: entries.clone(),
+        };
+
+        let observable =
+            Observable::<u8>::new_persisted(0, EVENT_NAME, Persistence::new(store, KEY, DEBOUNCE))
+                .await
+                .unwrap();
+        assert_eq!(observable, 3);
+
+        observable.set(4).await;
+        observable.set(5).await;
+        tokio::time::sleep(DEBOUNCE * 4).await;
+
+        assert_eq!(entries.lock().get(KEY), Some(&b"5".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn fall_back_to_default() {
+        let persistence = Persistence::new(MemoryStore::default(), KEY, DEBOUNCE);
+        let observable = Observable::<u8>::new_persisted(7, EVENT_NAME, persistence)
+            .await
+            .unwrap();
+        assert_eq!(observable, 7);
+    }
+
+    #[tokio::test]
+    async fn save_pending_value_on_drop() {
+        let entries = Arc::new(Mutex::new(HashMap::new()));
+        let store = MemoryStore {
+            entries: entries.clone(),
+        };
+
+        let observable = Observable::<u8>::new_persisted(
+            0,
+            EVENT_NAME,
+            Persistence::new(store, KEY, Duration::from_secs(60)),
+        )
+        .await
+        .unwrap();
+        observable.set(9).await;
+        drop(observable);
+        tokio::time::sleep(DEBOUNCE).await;
+
+        assert_eq!(entries.lock().get(KEY), Some(&b"9".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn replace_file_on_save() {
+        let directory = std::env::temp_dir().join(format!("lum_event_{}", std::process::id()));
+        let store = FileStore::new(&directory);
+
+        store.save(KEY, b"1".to_vec()).await.unwrap();
+        store.save(KEY, b"2".to_vec()).await.unwrap();
+        assert_eq!(store.load(KEY).await.unwrap(), Some(b"2".to_vec()));
+        assert!(!directory.join(format!("{}.json.tmp", KEY)).exists());
+
+        fs::remove_dir_all(&directory).await.unwrap();
+    }
+}
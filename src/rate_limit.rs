@@ -0,0 +1,142 @@
+use std::{collections::VecDeque, time::Duration};
+
+use lum_libs::{parking_lot::Mutex, tokio::time::Instant};
+
+use crate::runtime::{Runtime, sleep_until};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitPolicy {
+    Drop,
+    Queue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_dispatches: usize,
+    pub per: Duration,
+    pub policy: RateLimitPolicy,
+}
+
+impl RateLimit {
+    pub fn new(max_dispatches: usize, per: Duration, policy: RateLimitPolicy) -> Self {
+        Self {
+            max_dispatches,
+            per,
+            policy,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    dispatch_times: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            dispatch_times: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    // Returns false if the dispatch has to be dropped
+    pub async fn acquire(&self, runtime: &dyn Runtime) -> bool {
+        loop {
+            let wait_until = {
+                let now = Instant::now();
+                let mut dispatch_times = self.dispatch_times.lock();
+                while let Some(oldest) = dispatch_times.front() {
+                    if now.duration_since(*oldest) < self.limit.per {
+                        break;
+                    }
+
+                    dispatch_times.pop_front();
+                }
+
+                if dispatch_times.len() < self.limit.max_dispatches {
+                    dispatch_times.push_back(now);
+                    return true;
+                }
+
+                if self.limit.policy == RateLimitPolicy::Drop {
+                    return false;
+                }
+
+                match dispatch_times.front() {
+                    Some(oldest) => *oldest + self.limit.per,
+                    None => return false, // max_dispatches is 0, nothing will ever pass
+                }
+            };
+
+            sleep_until(runtime, wait_until).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Event,
+        subscriber::{DispatchError, DispatchFailure},
+    };
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const CLOSURE_NAME: &str = "test_closure";
+    const PERIOD: Duration = Duration::from_millis(100);
+
+    #[tokio::test]
+    async fn drop_excess_dispatches() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        event.set_rate_limit(Some(RateLimit::new(2, PERIOD, RateLimitPolicy::Drop)));
+
+        assert!(event.dispatch(1).await.is_ok());
+        assert!(event.dispatch(2).await.is_ok());
+
+        let errors = event.dispatch(3).await.unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [DispatchFailure {
+                subscriber: None,
+                error: DispatchError::RateLimited(3),
+            }]
+        ));
+
+        tokio::time::sleep(PERIOD).await;
+        assert!(event.dispatch(4).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn queue_excess_dispatches() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event.set_rate_limit(Some(RateLimit::new(2, PERIOD, RateLimitPolicy::Queue)));
+
+        let start = Instant::now();
+        for i in 0..3 {
+            event.dispatch(i).await.unwrap();
+        }
+
+        assert!(start.elapsed() >= PERIOD);
+    }
+
+    #[tokio::test]
+    async fn remove_rate_limit() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event.set_rate_limit(Some(RateLimit::new(0, PERIOD, RateLimitPolicy::Drop)));
+        assert!(event.dispatch(1).await.is_err());
+
+        event.set_rate_limit(None);
+        assert!(event.rate_limit().is_none());
+        assert!(event.dispatch(1).await.is_ok());
+    }
+}
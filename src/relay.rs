@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use lum_libs::tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use thiserror::Error;
+
+use crate::{Event, event::EventHandle, runtime::Runtime};
+
+#[derive(Debug, Error)]
+pub enum RelayError {
+    #[error("The relay task of event {event_name} is no longer running")]
+    TaskStopped { event_name: String },
+}
+
+// Queues dispatched data for a task that decides when it reaches the event.
+// Only the timing loop differs between the relaying events.
+pub(crate) struct Relay<T: Clone + Send + 'static> {
+    queue_sender: UnboundedSender<T>,
+}
+
+impl<T: Clone + Send + 'static> Relay<T> {
+    pub(crate) fn spawn<F, Fut>(event: &Event<T>, run: F) -> Self
+    where
+        F: FnOnce(Arc<dyn Runtime>, EventHandle<T>, UnboundedReceiver<T>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (queue_sender, queue_receiver) = unbounded_channel::<T>();
+        let runtime = event.runtime().clone();
+        runtime.spawn(Box::pin(run(
+            runtime.clone(),
+            event.handle(),
+            queue_receiver,
+        )));
+
+        Self { queue_sender }
+    }
+
+    pub(crate) fn send(&self, event: &Event<T>, data: T) -> Result<(), RelayError> {
+        self.queue_sender
+            .send(data)
+            .map_err(|_| RelayError::TaskStopped {
+                event_name: event.name().to_string(),
+            })
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    pin::pin,
+    time::Duration,
+};
+
+use lum_libs::tokio::{
+    select,
+    sync::mpsc::{UnboundedSender, unbounded_channel},
+    time::Instant,
+};
+use thiserror::Error;
+
+use crate::{
+    event::EventInner,
+    runtime::{sleep_until, timeout_at},
+    subscriber::DispatchFailure,
+};
+
+#[derive(Debug, Error)]
+pub enum RespondError {
+    #[error("The requester is no longer waiting for responses")]
+    RequesterGone,
+}
+
+pub struct Request<T, R> {
+    data: T,
+    reply_sender: UnboundedSender<R>,
+}
+
+impl<T, R> Request<T, R> {
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    pub fn respond(&self, response: R) -> Result<(), RespondError> {
+        self.reply_sender
+            .send(response)
+            .map_err(|_| RespondError::RequesterGone)
+    }
+
+    pub fn is_requester_waiting(&self) -> bool {
+        !self.reply_sender.is_closed()
+    }
+}
+
+impl<T: Clone, R> Clone for Request<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            reply_sender: self.reply_sender.clone(),
+        }
+    }
+}
+
+impl<T: Debug, R> Debug for Request<T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Request").field("data", &self.data).finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct Responses<T, R> {
+    pub responses: Vec<R>,
+    pub errors: Vec<DispatchFailure<Request<T, R>>>,
+    pub timed_out: bool,
+}
+
+impl<T: Clone + Send, R: Send> EventInner<Request<T, R>> {
+    pub async fn dispatch_request(&self, data: T, timeout: Duration) -> Responses<T, R> {
+        let deadline = Instant::now() + timeout;
+        let (reply_sender, mut reply_receiver) = unbounded_channel();
+        let request = Request { data, reply_sender };
+
+        let mut errors = Vec::new();
+        let mut timed_out = false;
+        let runtime = &**self.runtime();
+        match timeout_at(runtime, deadline, self.dispatch(request)).await {
+            Some(Ok(())) => {}
+            Some(Err(dispatch_errors)) => errors = dispatch_errors,
+            None => timed_out = true,
+        }
+
+        // Collect until every responder copy of the request has been dropped or the deadline is hit
+        let mut responses = Vec::new();
+        while !timed_out {
+            match timeout_at(runtime, deadline, reply_receiver.recv()).await {
+                Some(Some(response)) => responses.push(response),
+                Some(None) => break,
+                None => timed_out = true,
+            }
+        }
+
+        while let Ok(response) = reply_receiver.try_recv() {
+            responses.push(response);
+        }
+
+        Responses {
+            responses,
+            errors,
+            timed_out,
+        }
+    }
+
+    pub async fn dispatch_request_first(&self, data: T, timeout: Duration) -> Option<R> {
+        let deadline = Instant::now() + timeout;
+        let (reply_sender, mut reply_receiver) = unbounded_channel();
+        let request = Request { data, reply_sender };
+
+        let mut dispatch = pin!(self.dispatch(request));
+        let mut dispatched = false;
+        loop {
+            select! {
+                response = reply_receiver.recv() => return response,
+                _ = &mut dispatch, if !dispatched => dispatched = true,
+                _ = sleep_until(&**self.runtime(), deadline) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio::{self, spawn, time::sleep};
+
+    const EVENT_NAME: &str = "test_request_event";
+    const CLOSURE_NAME: &str = "test_closure";
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    #[tokio::test]
+    async fn collect_responses_from_all_subscribers() {
+        let event = Event::<Request<u32, u32>>::new(EVENT_NAME);
+        for factor in 1..=3 {
+            event
+                .subscribe_closure(
+                    CLOSURE_NAME,
+                    move |request| Ok(request.respond(request.data() * factor)?),
+                    false,
+                    false,
+                )
+                .unwrap();
+        }
+
+        let result = event.dispatch_request(2, TIMEOUT).await;
+        let mut responses = result.responses;
+        responses.sort();
+
+        assert_eq!(responses, vec![2, 4, 6]);
+        assert!(result.errors.is_empty());
+        assert!(!result.timed_out);
+    }
+
+    #[tokio::test]
+    async fn collect_responses_from_channel_subscribers() {
+        let event = Event::<Request<u32, u32>>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 1, false, false)
+            .unwrap();
+        spawn(async move {
+            while let Some(request) = receiver.recv().await {
+                request.respond(request.data() + 1).unwrap();
+            }
+        });
+
+        let result = event.dispatch_request(1, TIMEOUT).await;
+        assert_eq!(result.responses, vec![2]);
+        assert!(!result.timed_out);
+    }
+
+    #[tokio::test]
+    async fn time_out_waiting_for_responses() {
+        let event = Event::<Request<u32, u32>>::new(EVENT_NAME);
+        let (_, receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        let result = event.dispatch_request(1, TIMEOUT).await;
+        assert!(result.responses.is_empty());
+        assert!(result.timed_out);
+
+        drop(receiver);
+    }
+
+    #[tokio::test]
+    async fn return_first_response() {
+        let event = Event::<Request<u32, &str>>::new(EVENT_NAME);
+        event
+            .subscribe_async_closure(
+                CLOSURE_NAME,
+                |request| {
+                    Box::pin(async move {
+                        sleep(TIMEOUT / 2).await;
+                        Ok(request.respond("slow")?)
+                    })
+                },
+                false,
+                false,
+            )
+            .unwrap();
+        let (_, mut receiver) = event
+            .subscribe_channel(CLOSURE_NAME, 1, false, false)
+            .unwrap();
+        spawn(async move {
+            while let Some(request) = receiver.recv().await {
+                request.respond("fast").unwrap();
+            }
+        });
+
+        let response = event.dispatch_request_first(1, TIMEOUT).await;
+        assert!(response.is_some());
+    }
+
+    #[tokio::test]
+    async fn return_none_without_responders() {
+        let event = Event::<Request<u32, u32>>::new(EVENT_NAME);
+        let response = event.dispatch_request_first(1, TIMEOUT).await;
+        assert!(response.is_none());
+    }
+}
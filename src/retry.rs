@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RetryError {
+    #[error("The retry multiplier has to be finite and not negative, got {0}")]
+    InvalidMultiplier(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Retry {
+    // Includes the first delivery, so 1 means no retries
+    pub attempts: usize,
+    pub initial_delay: Duration,
+    // Applied to the delay after every failed attempt
+    pub multiplier: f64,
+    // Delays never grow past this
+    pub max_delay: Duration,
+}
+
+impl Retry {
+    pub fn new(
+        attempts: usize,
+        initial_delay: Duration,
+        multiplier: f64,
+    ) -> Result<Self, RetryError> {
+        if !multiplier.is_finite() || multiplier < 0.0 {
+            return Err(RetryError::InvalidMultiplier(multiplier));
+        }
+
+        Ok(Self {
+            attempts,
+            initial_delay,
+            multiplier,
+            max_delay: Duration::MAX,
+        })
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    // The fields are public, so a multiplier that slipped past new() caps the delay instead of panicking
+    pub(crate) fn delays(&self) -> impl Iterator<Item = Duration> {
+        let multiplier = self.multiplier;
+        let max_delay = self.max_delay;
+        std::iter::successors(Some(self.initial_delay.min(max_delay)), move |delay| {
+            let next =
+                Duration::try_from_secs_f64(delay.as_secs_f64() * multiplier).unwrap_or(max_delay);
+            Some(next.min(max_delay))
+        })
+        .take(self.attempts.saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::{Event, Subscriber};
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+    const DELAY: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn grow_delays_by_multiplier() {
+        let retry = Retry::new(4, DELAY, 2.0).unwrap();
+        let delays: Vec<_> = retry.delays().collect();
+        assert_eq!(delays, [DELAY, DELAY * 2, DELAY * 4]);
+    }
+
+    #[test]
+    fn reject_invalid_multiplier() {
+        assert!(Retry::new(2, DELAY, -1.0).is_err());
+        assert!(Retry::new(2, DELAY, f64::NAN).is_err());
+        assert!(Retry::new(2, DELAY, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn cap_delays_at_max_delay() {
+        let retry = Retry::new(5, DELAY, f64::MAX)
+            .unwrap()
+            .with_max_delay(DELAY * 3);
+        let delays: Vec<_> = retry.delays().collect();
+        assert_eq!(delays, [DELAY, DELAY * 3, DELAY * 3, DELAY * 3]);
+    }
+
+    #[tokio::test]
+    async fn retry_transient_errors() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let closure_attempts = attempts.clone();
+        let subscriber = Subscriber::builder(SUBSCRIBER_NAME)
+            .retry(Retry::new(3, DELAY, 2.0).unwrap())
+            .closure(
+                move |_data| match closure_attempts.fetch_add(1, Ordering::Relaxed) {
+                    0 | 1 => Err("busy".into()),
+                    _ => Ok(()),
+                },
+            );
+        event.subscribe(subscriber).unwrap();
+
+        event.dispatch(1).await.unwrap();
+        assert_eq!(attempts.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn fail_after_last_attempt() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let closure_attempts = attempts.clone();
+        let subscriber = Subscriber::builder(SUBSCRIBER_NAME)
+            .log_on_error(false)
+            .retry(Retry::new(2, DELAY, 2.0).unwrap())
+            .closure(move |_data| {
+                closure_attempts.fetch_add(1, Ordering::Relaxed);
+                Err("down".into())
+            });
+        event.subscribe(subscriber).unwrap();
+
+        assert!(event.dispatch(1).await.is_err());
+        assert_eq!(attempts.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::{Arc, Weak},
+};
+
+use lum_libs::dashmap::DashMap;
+
+use crate::{
+    Event, Subscriber,
+    event::{EventHandle, SubscribeError},
+    id::get_unique_id,
+    subscriber::Callback,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Segment {
+    Literal(String),
+    Single,
+    Multi,
+}
+
+// Dot separated topic pattern, "*" matches exactly one segment and "**" any number of segments
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TopicPattern {
+    pattern: String,
+    segments: Vec<Segment>,
+}
+
+impl TopicPattern {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let segments = pattern
+            .split('.')
+            .map(|segment| match segment {
+                "*" => Segment::Single,
+                "**" => Segment::Multi,
+                literal => Segment::Literal(literal.to_string()),
+            })
+            .collect();
+
+        Self { pattern, segments }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn matches(&self, topic: &str) -> bool {
+        let topic: Vec<&str> = topic.split('.').collect();
+        matches_segments(&self.segments, &topic)
+    }
+}
+
+fn matches_segments(segments: &[Segment], topic: &[&str]) -> bool {
+    match segments.split_first() {
+        None => topic.is_empty(),
+        Some((Segment::Multi, rest)) => {
+            (0..=topic.len()).any(|skipped| matches_segments(rest, &topic[skipped..]))
+        }
+        Some((segment, rest)) => match topic.split_first() {
+            Some((first, topic_rest)) => {
+                let segment_matches = match segment {
+                    Segment::Literal(literal) => literal == first,
+                    _ => true,
+                };
+
+                segment_matches && matches_segments(rest, topic_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+impl From<&str> for TopicPattern {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for TopicPattern {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl Display for TopicPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Routed<T> {
+    pub topic: Arc<str>,
+    pub data: T,
+}
+
+struct Route<T: Clone + Send> {
+    pattern: TopicPattern,
+    event_handle: EventHandle<Routed<T>>,
+}
+
+struct RouterInner<T: Clone + Send> {
+    name: String,
+    routes: DashMap<u64, Route<T>>,
+}
+
+impl<T: Clone + Send> RouterInner<T> {
+    async fn route(&self, topic: Arc<str>, data: T) {
+        // Collect the matching handles first so no map locks are held across await points
+        let mut matching = Vec::new();
+        let mut dropped = Vec::new();
+        for ref_multi in self.routes.iter() {
+            let route = ref_multi.value();
+            if route.event_handle.is_dropped() {
+                dropped.push(*ref_multi.key());
+            } else if route.pattern.matches(&topic) {
+                matching.push(route.event_handle.clone());
+            }
+        }
+
+        for id in dropped.into_iter() {
+            self.routes.remove(&id);
+        }
+
+        for event_handle in matching.into_iter() {
+            let routed = Routed {
+                topic: topic.clone(),
+                data: data.clone(),
+            };
+
+            let _ = event_handle.dispatch_report(routed).await;
+        }
+    }
+}
+
+// Delivers payloads of every attached event to the pattern subscriptions matching the event's name
+pub struct EventRouter<T: Clone + Send + 'static> {
+    inner: Arc<RouterInner<T>>,
+}
+
+impl<T: Clone + Send + 'static> EventRouter<T> {
+    pub fn new(name: impl Into<String>) -> Self {
+        let inner = RouterInner {
+            name: name.into(),
+            routes: DashMap::new(),
+        };
+
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    pub fn route_count(&self) -> usize {
+        self.inner.routes.len()
+    }
+
+    // The hidden subscription is removed from the event once the router is dropped
+    pub fn attach(&self, event: &Event<T>) -> Result<u64, SubscribeError> {
+        let topic: Arc<str> = Arc::from(event.name());
+        let router: Weak<RouterInner<T>> = Arc::downgrade(&self.inner);
+
+        let subscriber = Subscriber::new(
+            self.name(),
+            false,
+            true,
+            Callback::AsyncClosure(Box::new(move |data| {
+                let topic = topic.clone();
+                let router = router.clone();
+
+                Box::pin(async move {
+                    if let Some(router) = router.upgrade() {
+                        router.route(topic, data).await;
+                    }
+
+                    Ok(())
+                })
+            })),
+        )
+        .with_owner(&self.inner);
+
+        event.subscribe(subscriber)
+    }
+
+    pub fn subscribe_pattern(&self, pattern: impl Into<TopicPattern>) -> Event<Routed<T>> {
+        let pattern = pattern.into();
+        let event = Event::new(format!("{} ({})", self.name(), pattern));
+
+        let route = Route {
+            pattern,
+            event_handle: event.handle(),
+        };
+        self.inner.routes.insert(get_unique_id(), route);
+
+        event
+    }
+}
+
+impl<T: Clone + Send + 'static> Display for EventRouter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EventRouter {} ({} routes)",
+            self.name(),
+            self.route_count()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio;
+
+    const ROUTER_NAME: &str = "test_router";
+    const RECEIVER_NAME: &str = "test_receiver";
+
+    #[test]
+    fn match_patterns() {
+        let single = TopicPattern::new("sensor.*");
+        assert!(single.matches("sensor.temperature"));
+        assert!(!single.matches("sensor"));
+        assert!(!single.matches("sensor.temperature.raw"));
+
+        let multi = TopicPattern::new("net.**.error");
+        assert!(multi.matches("net.error"));
+        assert!(multi.matches("net.tcp.error"));
+        assert!(multi.matches("net.tcp.socket.error"));
+        assert!(!multi.matches("net.tcp.warning"));
+
+        assert!(TopicPattern::new("**").matches("anything.at.all"));
+        assert!(TopicPattern::new("exact.topic").matches("exact.topic"));
+    }
+
+    #[tokio::test]
+    async fn route_by_event_name() {
+        let router = EventRouter::<u8>::new(ROUTER_NAME);
+        let temperature = Event::new("sensor.temperature");
+        let net_error = Event::new("net.tcp.error");
+        router.attach(&temperature).unwrap();
+        router.attach(&net_error).unwrap();
+
+        let sensors = router.subscribe_pattern("sensor.*");
+        let (_, mut receiver) = sensors
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        net_error.dispatch(1).await.unwrap();
+        temperature.dispatch(2).await.unwrap();
+
+        let routed = receiver.recv().await.unwrap();
+        assert_eq!(&*routed.topic, "sensor.temperature");
+        assert_eq!(routed.data, 2);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn drop_routes_and_router() {
+        let router = EventRouter::<u8>::new(ROUTER_NAME);
+        let event = Event::new("sensor.temperature");
+        router.attach(&event).unwrap();
+
+        drop(router.subscribe_pattern("**"));
+        event.dispatch(1).await.unwrap();
+        assert_eq!(router.route_count(), 0);
+
+        drop(router);
+        event.dispatch(1).await.unwrap();
+        assert_eq!(event.subscriber_count(), 0);
+    }
+}
@@ -0,0 +1,134 @@
+use std::{fmt::Debug, sync::Arc, thread, time::Duration};
+
+use lum_boxtypes::{BoxedError, BoxedErrorResult, PinnedBoxedFuture, PinnedBoxedFutureResult};
+use lum_libs::tokio::{self, select, sync::oneshot, time::Instant};
+
+pub type BlockingTask = Box<dyn FnOnce() -> BoxedErrorResult<()> + Send>;
+
+// Only spawning and timers are runtime specific. The tokio channels and Notify used internally
+// don't depend on the tokio executor, so they work on async-std and smol as well.
+pub trait Runtime: Debug + Send + Sync + 'static {
+    fn spawn(&self, future: PinnedBoxedFuture<()>);
+    fn sleep(&self, duration: Duration) -> PinnedBoxedFuture<()>;
+
+    // Runs the task where it can't stall the executor. Without a blocking pool, every task gets its own thread.
+    fn spawn_blocking(&self, task: BlockingTask) -> PinnedBoxedFutureResult<()> {
+        let (sender, receiver) = oneshot::channel();
+        thread::spawn(move || {
+            let _ = sender.send(task());
+        });
+
+        Box::pin(async move {
+            receiver
+                .await
+                .unwrap_or_else(|_| Err(BoxedError::from("The blocking task panicked")))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TokioRuntime;
+
+impl Runtime for TokioRuntime {
+    fn spawn(&self, future: PinnedBoxedFuture<()>) {
+        tokio::spawn(future);
+    }
+
+    fn sleep(&self, duration: Duration) -> PinnedBoxedFuture<()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    fn spawn_blocking(&self, task: BlockingTask) -> PinnedBoxedFutureResult<()> {
+        let handle = tokio::task::spawn_blocking(task);
+        Box::pin(async move { handle.await? })
+    }
+}
+
+pub(crate) fn default_runtime() -> Arc<dyn Runtime> {
+    Arc::new(TokioRuntime)
+}
+
+pub(crate) async fn sleep_until(runtime: &dyn Runtime, deadline: Instant) {
+    let duration = deadline.saturating_duration_since(Instant::now());
+    runtime.sleep(duration).await;
+}
+
+// Returns None if the deadline passed before the future completed
+pub(crate) async fn timeout_at<F: Future>(
+    runtime: &dyn Runtime,
+    deadline: Instant,
+    future: F,
+) -> Option<F::Output> {
+    select! {
+        biased;
+        output = future => Some(output),
+        _ = sleep_until(runtime, deadline) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{DebouncedEvent, EventRepeater};
+    use lum_libs::tokio::time::sleep;
+
+    const EVENT_NAME: &str = "test_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+
+    // Delegates to tokio while counting how often the crate goes through the runtime
+    #[derive(Debug, Default)]
+    struct CountingRuntime {
+        spawns: AtomicUsize,
+        sleeps: AtomicUsize,
+    }
+
+    impl Runtime for Arc<CountingRuntime> {
+        fn spawn(&self, future: PinnedBoxedFuture<()>) {
+            self.spawns.fetch_add(1, Ordering::Relaxed);
+            TokioRuntime.spawn(future);
+        }
+
+        fn sleep(&self, duration: Duration) -> PinnedBoxedFuture<()> {
+            self.sleeps.fetch_add(1, Ordering::Relaxed);
+            TokioRuntime.sleep(duration)
+        }
+    }
+
+    #[tokio::test]
+    async fn use_custom_runtime() {
+        let runtime = Arc::new(CountingRuntime::default());
+
+        let debounced = DebouncedEvent::<u8>::new_with_runtime(
+            EVENT_NAME,
+            Duration::from_millis(20),
+            runtime.clone(),
+        );
+        let (_, mut receiver) = debounced
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        debounced.dispatch(1).unwrap();
+        assert_eq!(receiver.recv().await, Some(1));
+
+        let _repeater = EventRepeater::<u8>::new_with_runtime(EVENT_NAME, runtime.clone());
+        sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(runtime.spawns.load(Ordering::Relaxed), 2);
+        assert!(runtime.sleeps.load(Ordering::Relaxed) > 0);
+    }
+
+    #[tokio::test]
+    async fn timeout_at_deadline() {
+        let runtime = TokioRuntime;
+        let deadline = Instant::now() + Duration::from_millis(20);
+
+        assert_eq!(timeout_at(&runtime, deadline, async { 1 }).await, Some(1));
+        assert_eq!(
+            timeout_at(&runtime, deadline, std::future::pending::<u8>()).await,
+            None
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU8, Ordering},
+};
+
+use lum_libs::tokio::{select, sync::Notify, time::Instant};
+
+use crate::{
+    event::EventHandle,
+    runtime::{Runtime, sleep_until},
+    subscriber::CallbackError,
+};
+
+const PENDING: u8 = 0;
+const DISPATCHED: u8 = 1;
+const CANCELLED: u8 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleState {
+    Pending,
+    Dispatched,
+    Cancelled,
+}
+
+#[derive(Debug)]
+pub struct ScheduledDispatch {
+    at: Instant,
+    state: Arc<AtomicU8>,
+    cancel_notify: Arc<Notify>,
+}
+
+impl ScheduledDispatch {
+    pub(crate) fn spawn<T: Clone + Send + 'static, E: CallbackError>(
+        runtime: Arc<dyn Runtime>,
+        event_handle: EventHandle<T, E>,
+        data: T,
+        at: Instant,
+    ) -> Self {
+        let state = Arc::new(AtomicU8::new(PENDING));
+        let cancel_notify = Arc::new(Notify::new());
+
+        let task_state = state.clone();
+        let task_cancel_notify = cancel_notify.clone();
+        let task_runtime = runtime.clone();
+        runtime.spawn(Box::pin(async move {
+            select! {
+                _ = sleep_until(&*task_runtime, at) => {
+                    let fired = task_state
+                        .compare_exchange(PENDING, DISPATCHED, Ordering::AcqRel, Ordering::Acquire)
+                        .is_ok();
+
+                    if fired {
+                        let _ = event_handle.dispatch(data).await;
+                    }
+                }
+                _ = task_cancel_notify.notified() => {}
+            }
+        }));
+
+        Self {
+            at,
+            state,
+            cancel_notify,
+        }
+    }
+
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+
+    pub fn state(&self) -> ScheduleState {
+        match self.state.load(Ordering::Acquire) {
+            PENDING => ScheduleState::Pending,
+            DISPATCHED => ScheduleState::Dispatched,
+            _ => ScheduleState::Cancelled,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.state() == ScheduleState::Pending
+    }
+
+    // Returns false if the dispatch already happened or was cancelled before
+    pub fn cancel(&self) -> bool {
+        let cancelled = self
+            .state
+            .compare_exchange(PENDING, CANCELLED, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok();
+
+        if cancelled {
+            self.cancel_notify.notify_one();
+        }
+
+        cancelled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio::{self, time::sleep};
+
+    const EVENT_NAME: &str = "test_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+    const DELAY: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn dispatch_after_delay() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        let start = Instant::now();
+        let scheduled = event.dispatch_after(1, DELAY);
+        assert!(scheduled.is_pending());
+        assert!(receiver.try_recv().is_err());
+
+        assert_eq!(receiver.recv().await.unwrap(), 1);
+        assert!(start.elapsed() >= DELAY);
+        assert_eq!(scheduled.state(), ScheduleState::Dispatched);
+        assert!(!scheduled.cancel());
+    }
+
+    #[tokio::test]
+    async fn dispatch_at_instant() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        let at = Instant::now() + DELAY;
+        let scheduled = event.dispatch_at(2, at);
+        assert_eq!(scheduled.at(), at);
+
+        assert_eq!(receiver.recv().await.unwrap(), 2);
+        assert!(Instant::now() >= at);
+    }
+
+    #[tokio::test]
+    async fn cancel_scheduled_dispatch() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (_, mut receiver) = event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        let scheduled = event.dispatch_after(3, DELAY);
+        assert!(scheduled.cancel());
+        assert_eq!(scheduled.state(), ScheduleState::Cancelled);
+        assert!(!scheduled.cancel());
+
+        sleep(DELAY * 2).await;
+        assert!(receiver.try_recv().is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use std::io;
+
+use lum_boxtypes::LifetimedPinnedBoxedFuture;
+use lum_libs::{
+    serde::Serialize,
+    tokio::{
+        io::{AsyncWrite, AsyncWriteExt},
+        sync::Mutex as AsyncMutex,
+    },
+};
+use thiserror::Error;
+
+use crate::{CustomCallback, subscriber::CallbackError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Framing {
+    // One JSON document per line
+    #[default]
+    Ndjson,
+    // Every JSON document is preceded by its length as a big-endian u32
+    LengthPrefixed,
+}
+
+#[derive(Debug, Error)]
+pub enum WriteError {
+    #[error("Failed to serialize data: {0}")]
+    Serialize(#[from] serde_json::Error),
+
+    #[error("Failed to write data: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("The serialized data is {0} bytes long, which doesn't fit into a length prefix")]
+    FrameTooLarge(usize),
+}
+
+impl Framing {
+    fn encode<T: Serialize>(&self, data: &T) -> Result<Vec<u8>, WriteError> {
+        match self {
+            Framing::Ndjson => {
+                let mut frame = serde_json::to_vec(data)?;
+                frame.push(b'\n');
+                Ok(frame)
+            }
+            Framing::LengthPrefixed => {
+                let json = serde_json::to_vec(data)?;
+                let length =
+                    u32::try_from(json.len()).map_err(|_| WriteError::FrameTooLarge(json.len()))?;
+
+                let mut frame = Vec::with_capacity(4 + json.len());
+                frame.extend_from_slice(&length.to_be_bytes());
+                frame.extend_from_slice(&json);
+                Ok(frame)
+            }
+        }
+    }
+}
+
+// Tees dispatched data into a file or socket. Every frame is flushed right away, so buffered
+// writers don't lose data when they are dropped.
+pub struct SerializeWriter<W> {
+    writer: AsyncMutex<W>,
+    framing: Framing,
+}
+
+impl<W: AsyncWrite + Unpin + Send> SerializeWriter<W> {
+    pub fn new(writer: W, framing: Framing) -> Self {
+        Self {
+            writer: AsyncMutex::new(writer),
+            framing,
+        }
+    }
+
+    pub fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+
+    async fn write(&self, frame: &[u8]) -> Result<(), WriteError> {
+        let mut writer = self.writer.lock().await;
+        writer.write_all(frame).await?;
+        writer.flush().await?;
+
+        Ok(())
+    }
+}
+
+impl<T, E, W> CustomCallback<T, E> for SerializeWriter<W>
+where
+    T: Serialize + Send,
+    E: CallbackError + From<WriteError>,
+    W: AsyncWrite + Unpin + Send,
+{
+    fn deliver<'a>(&'a self, data: T) -> LifetimedPinnedBoxedFuture<'a, Result<(), E>>
+    where
+        T: 'a,
+    {
+        Box::pin(async move {
+            let frame = self.framing.encode(&data).map_err(E::from)?;
+            self.write(&frame).await.map_err(E::from)
+        })
+    }
+
+    fn flush<'a>(&'a self) -> LifetimedPinnedBoxedFuture<'a, ()> {
+        Box::pin(async move {
+            let _ = self.writer.lock().await.flush().await;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio::{
+        self,
+        io::{AsyncBufReadExt, AsyncReadExt, BufReader},
+    };
+
+    const EVENT_NAME: &str = "test_event";
+    const WRITER_NAME: &str = "test_writer";
+
+    #[tokio::test]
+    async fn write_framed_payloads() {
+        let event = Event::<Vec<u8>>::new(EVENT_NAME);
+        let (ndjson_writer, ndjson_reader) = tokio::io::duplex(1024);
+        let (prefixed_writer, mut prefixed_reader) = tokio::io::duplex(1024);
+        event
+            .subscribe_writer(WRITER_NAME, ndjson_writer, Framing::Ndjson, false, false)
+            .unwrap();
+        event
+            .subscribe_writer(
+                WRITER_NAME,
+                prefixed_writer,
+                Framing::LengthPrefixed,
+                false,
+                false,
+            )
+            .unwrap();
+
+        event.dispatch(vec![1, 2]).await.unwrap();
+        event.dispatch(vec![3]).await.unwrap();
+
+        let mut lines = BufReader::new(ndjson_reader).lines();
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "[1,2]");
+        assert_eq!(lines.next_line().await.unwrap().unwrap(), "[3]");
+
+        let length = prefixed_reader.read_u32().await.unwrap();
+        let mut json = vec![0; length as usize];
+        prefixed_reader.read_exact(&mut json).await.unwrap();
+        assert_eq!(json, b"[1,2]");
+    }
+}
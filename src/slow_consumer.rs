@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use crate::subscriber::SubscriberInfo;
+
+pub type SlowConsumerHandler = dyn Fn(&SlowConsumerWarning) + Send + Sync;
+
+// Decides when a channel subscriber counts as a slow consumer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlowConsumerDetection {
+    // Share of the channel buffer that has to be in use after a send for it to count as congested
+    pub fill_ratio: f64,
+    // Sends that block at least this long count as congested as well
+    pub block_time: Duration,
+    // Congested sends in a row before the subscriber is reported. 0 counts as 1.
+    pub consecutive: usize,
+}
+
+impl SlowConsumerDetection {
+    pub fn new(fill_ratio: f64, block_time: Duration, consecutive: usize) -> Self {
+        Self {
+            fill_ratio,
+            block_time,
+            consecutive: consecutive.max(1),
+        }
+    }
+
+    pub(crate) fn is_congested(
+        &self,
+        buffered: usize,
+        capacity: usize,
+        blocked_for: Duration,
+    ) -> bool {
+        buffered as f64 >= capacity as f64 * self.fill_ratio || blocked_for >= self.block_time
+    }
+
+    pub(crate) fn is_reported(&self, congested_sends: usize) -> bool {
+        congested_sends == self.consecutive.max(1)
+    }
+}
+
+impl Default for SlowConsumerDetection {
+    fn default() -> Self {
+        Self {
+            fill_ratio: 0.9,
+            block_time: Duration::from_millis(10),
+            consecutive: 10,
+        }
+    }
+}
+
+// Reported once per streak of congested sends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlowConsumerWarning {
+    pub event_name: String,
+    pub subscriber: SubscriberInfo,
+    pub buffered: usize,
+    pub capacity: usize,
+    pub blocked_for: Duration,
+    pub congested_sends: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio::{self, sync::mpsc::unbounded_channel};
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[tokio::test]
+    async fn warn_about_full_channel() {
+        let detection = SlowConsumerDetection::new(0.5, Duration::from_secs(60), 2);
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .slow_consumer_detection(detection)
+            .build();
+        let (warning_sender, mut warning_receiver) = unbounded_channel();
+        event.set_slow_consumer_handler(move |warning| {
+            let _ = warning_sender.send(warning.clone());
+        });
+
+        let (id, _receiver) = event
+            .subscribe_channel(SUBSCRIBER_NAME, 4, false, false)
+            .unwrap();
+        for data in 1..=4 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        let warning = warning_receiver.recv().await.unwrap();
+        assert_eq!(warning.subscriber.id, id);
+        assert_eq!(warning.buffered, 3);
+        assert_eq!(warning.capacity, 4);
+        assert_eq!(warning.congested_sends, 2);
+        assert!(warning_receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn warn_on_first_congested_send_without_consecutive() {
+        let detection = SlowConsumerDetection {
+            fill_ratio: 0.5,
+            block_time: Duration::from_secs(60),
+            consecutive: 0,
+        };
+        let event = Event::<u8>::builder(EVENT_NAME)
+            .slow_consumer_detection(detection)
+            .build();
+        let (warning_sender, mut warning_receiver) = unbounded_channel();
+        event.set_slow_consumer_handler(move |warning| {
+            let _ = warning_sender.send(warning.clone());
+        });
+
+        let (_, _receiver) = event
+            .subscribe_channel(SUBSCRIBER_NAME, 2, false, false)
+            .unwrap();
+        event.dispatch(1).await.unwrap();
+
+        let warning = warning_receiver.recv().await.unwrap();
+        assert_eq!(warning.congested_sends, 1);
+    }
+}
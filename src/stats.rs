@@ -0,0 +1,241 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use lum_libs::{dashmap::DashMap, parking_lot::Mutex, tokio::time::Instant};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventStats {
+    pub total_dispatches: u64,
+    pub total_errors: u64,
+    pub subscriber_failures: HashMap<u64, u64>,
+    pub last_dispatch_duration: Option<Duration>,
+    pub last_dispatch_at: Option<Instant>,
+    pub subscribers: HashMap<u64, SubscriberStats>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriberStats {
+    pub calls: u64,
+    pub errors: u64,
+    pub last_error: Option<String>,
+    pub last_handled_at: Option<Instant>,
+    // Copied from the subscriber, to group its numbers by dimension
+    pub labels: Arc<BTreeMap<String, String>>,
+}
+
+impl EventStats {
+    pub fn time_since_last_dispatch(&self) -> Option<Duration> {
+        self.last_dispatch_at
+            .map(|last_dispatch_at| last_dispatch_at.elapsed())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct StatsRecorder {
+    total_dispatches: AtomicU64,
+    total_errors: AtomicU64,
+    subscriber_failures: DashMap<u64, u64>,
+    subscribers: DashMap<u64, SubscriberStats>,
+    last_dispatch: Mutex<Option<(Instant, Duration)>>,
+}
+
+impl StatsRecorder {
+    pub fn record_dispatch(&self, duration: Duration, error_count: usize) {
+        self.total_dispatches.fetch_add(1, Ordering::Relaxed);
+        self.total_errors
+            .fetch_add(error_count as u64, Ordering::Relaxed);
+        *self.last_dispatch.lock() = Some((Instant::now(), duration));
+    }
+
+    pub fn record_subscriber_call(
+        &self,
+        subscriber_id: u64,
+        labels: &BTreeMap<String, String>,
+        error: Option<String>,
+    ) {
+        let mut stats = self
+            .subscribers
+            .entry(subscriber_id)
+            .or_insert_with(|| SubscriberStats {
+                labels: Arc::new(labels.clone()),
+                ..SubscriberStats::default()
+            });
+        stats.calls += 1;
+        stats.last_handled_at = Some(Instant::now());
+
+        if let Some(error) = error {
+            stats.errors += 1;
+            stats.last_error = Some(error);
+            *self.subscriber_failures.entry(subscriber_id).or_insert(0) += 1;
+        }
+    }
+
+    pub fn forget_subscriber(&self, subscriber_id: u64) {
+        self.subscriber_failures.remove(&subscriber_id);
+        self.subscribers.remove(&subscriber_id);
+    }
+
+    pub fn subscriber_snapshot(&self, subscriber_id: u64) -> Option<SubscriberStats> {
+        self.subscribers
+            .get(&subscriber_id)
+            .map(|ref_multi| ref_multi.value().clone())
+    }
+
+    pub fn snapshot(&self) -> EventStats {
+        let subscriber_failures = self
+            .subscriber_failures
+            .iter()
+            .map(|ref_multi| (*ref_multi.key(), *ref_multi.value()))
+            .collect();
+
+        let subscribers = self
+            .subscribers
+            .iter()
+            .map(|ref_multi| (*ref_multi.key(), ref_multi.value().clone()))
+            .collect();
+
+        let last_dispatch = *self.last_dispatch.lock();
+
+        EventStats {
+            total_dispatches: self.total_dispatches.load(Ordering::Relaxed),
+            total_errors: self.total_errors.load(Ordering::Relaxed),
+            subscriber_failures,
+            last_dispatch_duration: last_dispatch.map(|(_, duration)| duration),
+            last_dispatch_at: last_dispatch.map(|(at, _)| at),
+            subscribers,
+        }
+    }
+
+    pub fn reset(&self) {
+        self.total_dispatches.store(0, Ordering::Relaxed);
+        self.total_errors.store(0, Ordering::Relaxed);
+        self.subscriber_failures.clear();
+        self.subscribers.clear();
+        *self.last_dispatch.lock() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Event, Subscriber};
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const CLOSURE_NAME: &str = "test_closure";
+
+    #[tokio::test]
+    async fn count_dispatches_and_errors() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event
+            .subscribe_closure(CLOSURE_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        let failing_id = event
+            .subscribe_closure(CLOSURE_NAME, |_data| Err("test_error".into()), false, false)
+            .unwrap();
+
+        let stats = event.stats();
+        assert_eq!(stats.total_dispatches, 0);
+        assert!(stats.last_dispatch_duration.is_none());
+        assert!(stats.time_since_last_dispatch().is_none());
+
+        for i in 0..3 {
+            assert!(event.dispatch(i).await.is_err());
+        }
+
+        let stats = event.stats();
+        assert_eq!(stats.total_dispatches, 3);
+        assert_eq!(stats.total_errors, 3);
+        assert_eq!(stats.subscriber_failures.get(&failing_id), Some(&3));
+        assert_eq!(stats.subscriber_failures.len(), 1);
+        assert!(stats.last_dispatch_duration.is_some());
+        assert!(stats.last_dispatch_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn track_last_dispatch_time() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let before = Instant::now();
+        event.dispatch(1).await.unwrap();
+
+        let stats = event.stats();
+        let last_dispatch_at = stats.last_dispatch_at.unwrap();
+        assert!(last_dispatch_at >= before);
+        assert!(stats.time_since_last_dispatch().unwrap() <= before.elapsed());
+    }
+
+    #[tokio::test]
+    async fn track_subscriber_stats() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let id = event
+            .subscribe_closure(
+                CLOSURE_NAME,
+                |data| match data {
+                    0 => Err("test_error".into()),
+                    _ => Ok(()),
+                },
+                false,
+                false,
+            )
+            .unwrap();
+        assert!(event.subscriber_stats(id).is_none());
+
+        assert!(event.dispatch(0).await.is_err());
+        event.dispatch(1).await.unwrap();
+
+        let stats = event.subscriber_stats(id).unwrap();
+        assert_eq!(stats.calls, 2);
+        assert_eq!(stats.errors, 1);
+        assert!(stats.last_error.unwrap().contains("test_error"));
+        assert!(stats.last_handled_at.is_some());
+        assert_eq!(event.stats().subscribers.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn label_subscriber_stats() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let subscriber = Subscriber::builder(CLOSURE_NAME)
+            .label("team", "billing")
+            .closure(|_data| Ok(()));
+        assert_eq!(subscriber.labels().get("team").unwrap(), "billing");
+        let id = event.subscribe(subscriber).unwrap();
+
+        event.dispatch(1).await.unwrap();
+
+        let stats = event.stats();
+        let labels = &stats.subscribers[&id].labels;
+        assert_eq!(labels.get("team").map(String::as_str), Some("billing"));
+    }
+
+    #[tokio::test]
+    async fn forget_unsubscribed_subscribers() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let failing_id = event
+            .subscribe_closure(CLOSURE_NAME, |_data| Err("test_error".into()), false, false)
+            .unwrap();
+
+        assert!(event.dispatch(1).await.is_err());
+        assert_eq!(event.stats().subscriber_failures.len(), 1);
+
+        event.unsubscribe(failing_id);
+        let stats = event.stats();
+        assert!(stats.subscriber_failures.is_empty());
+        assert_eq!(stats.total_errors, 1);
+    }
+
+    #[tokio::test]
+    async fn reset_stats() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        event.dispatch(1).await.unwrap();
+        assert_eq!(event.stats().total_dispatches, 1);
+
+        event.reset_stats();
+        assert_eq!(event.stats(), Default::default());
+    }
+}
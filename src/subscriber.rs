@@ -1,44 +1,309 @@
-use lum_boxtypes::{BoxedError, BoxedErrorResult, PinnedBoxedFutureResult};
-use lum_libs::tokio::sync::mpsc::{Sender, error::SendError};
+use std::{
+    any::Any,
+    collections::{BTreeMap, VecDeque},
+    error::Error as StdError,
+    fmt::{self, Debug, Display, Formatter},
+    pin::pin,
+    sync::{
+        Arc, Weak,
+        atomic::{AtomicI32, AtomicU8, AtomicUsize, Ordering},
+    },
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use lum_boxtypes::{BoxedError, PinnedBoxedFuture};
+use lum_libs::{
+    parking_lot::Mutex,
+    tokio::sync::{
+        mpsc::{Sender, error::SendError},
+        oneshot,
+    },
+};
+use lum_log::error;
 use thiserror::Error;
 
-use crate::id::get_unique_id;
+#[cfg(feature = "futures")]
+use futures_util::{Sink, SinkExt};
+#[cfg(feature = "futures")]
+use lum_libs::tokio::sync::Mutex as AsyncMutex;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
+
+use crate::{
+    custom_callback::CustomCallback,
+    event_handler::EventHandler,
+    id::get_unique_id,
+    retry::Retry,
+    runtime::{BlockingTask, Runtime},
+    subscriber_builder::SubscriberBuilder,
+};
+
+// Error type returned by subscriber callbacks. Events use BoxedError unless a typed error is chosen.
+pub trait CallbackError: Display + Debug + Send + Sync + 'static {}
+
+impl<E: Display + Debug + Send + Sync + 'static> CallbackError for E {}
+
+pub type SubscriberFilter<T> = dyn Fn(&T) -> bool + Send + Sync;
+pub type SubscriberTransform<T> = dyn Fn(T) -> T + Send + Sync;
+pub type ClosureMutFn<T, E> = dyn FnMut(T) -> Result<(), E> + Send;
+pub type SubscriberErrorHook<T, E = BoxedError> = dyn Fn(&DispatchError<T, E>) + Send + Sync;
+
+pub type FlowClosure<T, E> = dyn Fn(T) -> Result<HandlerFlow, E> + Send + Sync;
+pub type AsyncFlowClosure<T, E> =
+    dyn Fn(T) -> PinnedBoxedFuture<Result<HandlerFlow, E>> + Send + Sync;
+
+const ACTIVE: u8 = 0;
+const PAUSED: u8 = 1;
+const UNSUBSCRIBED: u8 = 2;
+
+// Returned by flow closures to manage their own subscription
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HandlerFlow {
+    #[default]
+    Continue,
+    Unsubscribe,
+    // Skips payloads until the subscriber is resumed through the event
+    PauseSelf,
+}
+
+impl HandlerFlow {
+    fn apply(self, state: &AtomicU8) {
+        match self {
+            HandlerFlow::Continue => {}
+            HandlerFlow::Unsubscribe => state.store(UNSUBSCRIBED, Ordering::Release),
+            HandlerFlow::PauseSelf => {
+                let _ = state.compare_exchange(ACTIVE, PAUSED, Ordering::AcqRel, Ordering::Acquire);
+            }
+        }
+    }
+}
+
+// The task runs the closure and the receiver gets its result
+pub type BlockingCall<E> = (BlockingTask, oneshot::Receiver<Result<(), E>>);
 
-pub enum Callback<T> {
+pub enum Callback<T, E = BoxedError> {
     Channel(Sender<T>),
-    Closure(Box<dyn Fn(T) -> BoxedErrorResult<()> + Send + Sync>),
-    AsyncClosure(Box<dyn Fn(T) -> PinnedBoxedFutureResult<()> + Send + Sync>),
+    Closure(Box<dyn Fn(T) -> Result<(), E> + Send + Sync>),
+    // Calls are serialized by the mutex, so the closure only needs to be Send
+    ClosureMut(Mutex<Box<ClosureMutFn<T, E>>>),
+    AsyncClosure(Box<dyn Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync>),
+    // Runs in its own task, so the dispatch only waits for the future to be created.
+    // Errors of the spawned task can only be logged.
+    Spawned(Box<dyn Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync>),
+    // Binds the data to a task that runs on the runtime's blocking pool. Use Callback::blocking to create it.
+    BlockingClosure(Box<dyn Fn(T) -> BlockingCall<E> + Send + Sync>),
+    Handler(Arc<dyn EventHandler<T, E>>),
+    // Like Closure and AsyncClosure, but the returned HandlerFlow can unsubscribe or pause the subscriber
+    FlowClosure(Box<FlowClosure<T, E>>),
+    AsyncFlowClosure(Box<AsyncFlowClosure<T, E>>),
+    Custom(Box<dyn CustomCallback<T, E>>),
+    // Sends never block. A full channel fails the delivery just like a disconnected one.
+    #[cfg(feature = "crossbeam")]
+    Crossbeam(crossbeam_channel::Sender<T>),
+    // Every payload is sent and flushed before the dispatch continues. Use Callback::sink to create it.
+    #[cfg(feature = "futures")]
+    Sink(AsyncMutex<PinnedBoxedSink<T, E>>),
+}
+
+#[cfg(feature = "futures")]
+pub type PinnedBoxedSink<T, E = BoxedError> = Pin<Box<dyn Sink<T, Error = E> + Send>>;
+
+#[cfg(feature = "futures")]
+impl<T, E: CallbackError> Callback<T, E> {
+    pub fn sink<S, SE>(sink: S) -> Self
+    where
+        S: Sink<T, Error = SE> + Send + 'static,
+        SE: Into<E> + 'static,
+    {
+        let sink = sink.sink_map_err(Into::into);
+        Callback::Sink(AsyncMutex::new(Box::pin(sink)))
+    }
+}
+
+impl<T: Send + 'static, E: CallbackError> Callback<T, E> {
+    pub fn blocking(closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static) -> Self {
+        let closure = Arc::new(closure);
+
+        Callback::BlockingClosure(Box::new(move |data| {
+            let closure = closure.clone();
+            let (sender, receiver) = oneshot::channel();
+            let task: BlockingTask = Box::new(move || {
+                let _ = sender.send(closure(data));
+                Ok(())
+            });
+
+            (task, receiver)
+        }))
+    }
 }
 
 #[derive(Debug, Error)]
-pub enum DispatchError<T> {
+pub enum DispatchError<T, E = BoxedError> {
     #[error("Failed to send data to channel: {0}")]
     ChannelSend(#[from] SendError<T>),
 
     #[error("Failed to dispatch data to closure: {0}")]
-    Closure(BoxedError),
+    Closure(E),
 
     #[error("Failed to dispatch data to async closure: {0}")]
-    AsyncClosure(BoxedError),
+    AsyncClosure(E),
+
+    #[error("Failed to dispatch data to blocking closure: {0}")]
+    BlockingClosure(E),
+
+    // The runtime failed to run the blocking closure to completion, e.g. because it panicked
+    #[error("The blocking task failed: {0}")]
+    BlockingTask(BoxedError),
+
+    #[error("Failed to dispatch data to handler: {0}")]
+    Handler(E),
+
+    #[error("Failed to dispatch data to custom callback: {0}")]
+    Custom(E),
+
+    #[cfg(feature = "crossbeam")]
+    #[error("Failed to send data to crossbeam channel: {0}")]
+    CrossbeamSend(#[from] crossbeam_channel::TrySendError<T>),
+
+    #[cfg(feature = "futures")]
+    #[error("Failed to send data to sink: {0}")]
+    Sink(E),
+
+    #[error("The dispatch was dropped by the event's rate limit")]
+    RateLimited(T),
+
+    #[error("The subscriber did not finish within {0:?}")]
+    Timeout(Duration),
+}
+
+impl<T, E> DispatchError<T, E> {
+    // Closed channels and failed blocking tasks won't recover, so retrying them is pointless
+    pub fn is_transient(&self) -> bool {
+        match self {
+            DispatchError::ChannelSend(_)
+            | DispatchError::BlockingTask(_)
+            | DispatchError::RateLimited(_)
+            | DispatchError::Timeout(_) => false,
+            #[cfg(feature = "crossbeam")]
+            DispatchError::CrossbeamSend(err) => err.is_full(),
+            _ => true,
+        }
+    }
+}
+
+// Errors returned by a dispatch, tied to the subscriber that failed.
+// Event level errors like rate limiting have no subscriber.
+#[derive(Debug)]
+pub struct DispatchFailure<T, E = BoxedError> {
+    pub subscriber: Option<SubscriberInfo>,
+    pub error: DispatchError<T, E>,
+}
+
+impl<T, E> DispatchFailure<T, E> {
+    pub(crate) fn new(subscriber: SubscriberInfo, error: DispatchError<T, E>) -> Self {
+        Self {
+            subscriber: Some(subscriber),
+            error,
+        }
+    }
+
+    pub(crate) fn without_subscriber(error: DispatchError<T, E>) -> Self {
+        Self {
+            subscriber: None,
+            error,
+        }
+    }
+
+    pub fn into_error(self) -> DispatchError<T, E> {
+        self.error
+    }
+}
+
+impl<T, E: Display> Display for DispatchFailure<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.subscriber {
+            Some(subscriber) => write!(
+                f,
+                "Subscriber \"{}\" ({}) failed: {}",
+                subscriber.name, subscriber.id, self.error
+            ),
+            None => write!(f, "{}", self.error),
+        }
+    }
 }
 
-pub struct Subscriber<T: Clone + Send> {
+impl<T: Debug + Send + 'static, E: CallbackError> StdError for DispatchFailure<T, E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubscriberInfo {
+    pub id: u64,
+    pub name: Arc<str>,
+    pub group: Option<Arc<str>>,
+    pub metadata: Arc<BTreeMap<String, String>>,
+    pub labels: Arc<BTreeMap<String, String>>,
+    pub priority: i32,
+}
+
+pub struct Subscriber<T: Clone + Send, E: CallbackError = BoxedError> {
     id: u64,
-    name: String,
+    name: Arc<str>,
     log_on_error: bool,
     remove_on_error: bool,
-    callback: Callback<T>,
+    callback: Callback<T, E>,
+    owner: Option<Weak<dyn Any + Send + Sync>>,
+    group: Option<Arc<str>>,
+    timeout: Option<Duration>,
+    metadata: Arc<BTreeMap<String, String>>,
+    labels: Arc<BTreeMap<String, String>>,
+    max_calls: Option<usize>,
+    calls: AtomicUsize,
+    filter: Option<Box<SubscriberFilter<T>>>,
+    transform: Option<Box<SubscriberTransform<T>>>,
+    priority: AtomicI32,
+    dedup: Option<Dedup<T>>,
+    sample_every: Option<usize>,
+    samples: AtomicUsize,
+    congested_sends: AtomicUsize,
+    // Shared with replays to spawned flow closures
+    flow_state: Arc<AtomicU8>,
+    on_error: Option<Box<SubscriberErrorHook<T, E>>>,
+    retry: Option<Retry>,
+    // Dispatches that reach the subscriber while it replays wait here, behind the replayed data
+    replay_backlog: Mutex<Option<VecDeque<T>>>,
+}
+
+// Remembers the last accepted payload to skip consecutive duplicates
+struct Dedup<T> {
+    last: Mutex<Option<T>>,
+    eq: fn(&T, &T) -> bool,
 }
 
-impl<T: Clone + Send> Subscriber<T> {
+impl<T: Clone> Dedup<T> {
+    fn accepts(&self, data: &T) -> bool {
+        let mut last = self.last.lock();
+        if last.as_ref().is_some_and(|last| (self.eq)(last, data)) {
+            return false;
+        }
+
+        *last = Some(data.clone());
+        true
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Subscriber<T, E> {
     pub fn new(
         name: impl Into<String>,
         log_on_error: bool,
         remove_on_error: bool,
-        callback: Callback<T>,
+        callback: Callback<T, E>,
     ) -> Self {
         let id = get_unique_id();
-        let name = name.into();
+        let name = Arc::from(name.into());
 
         Self {
             id,
@@ -46,9 +311,127 @@ impl<T: Clone + Send> Subscriber<T> {
             log_on_error,
             remove_on_error,
             callback,
+            owner: None,
+            group: None,
+            timeout: None,
+            metadata: Arc::default(),
+            labels: Arc::default(),
+            max_calls: None,
+            calls: AtomicUsize::new(0),
+            filter: None,
+            transform: None,
+            priority: AtomicI32::new(0),
+            dedup: None,
+            sample_every: None,
+            samples: AtomicUsize::new(0),
+            congested_sends: AtomicUsize::new(0),
+            flow_state: Arc::new(AtomicU8::new(ACTIVE)),
+            on_error: None,
+            retry: None,
+            replay_backlog: Mutex::new(None),
         }
     }
 
+    pub fn builder(name: impl Into<String>) -> SubscriberBuilder<T, E> {
+        SubscriberBuilder::new(name)
+    }
+
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(Arc::from(group.into()));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    // Shows up in SubscriberInfo, e.g. to tell which module registered a subscriber
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.metadata).insert(key.into(), value.into());
+        self
+    }
+
+    // Dimensions for metrics and tracing, so dashboards don't depend on subscriber names
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.labels).insert(key.into(), value.into());
+        self
+    }
+
+    // Ordered events reach subscribers with a higher priority first
+    pub fn with_priority(self, priority: i32) -> Self {
+        self.priority.store(priority, Ordering::Relaxed);
+        self
+    }
+
+    pub(crate) fn with_dedup_by(mut self, eq: fn(&T, &T) -> bool) -> Self {
+        self.dedup = Some(Dedup {
+            last: Mutex::new(None),
+            eq,
+        });
+        self
+    }
+
+    // Only every nth accepted payload is delivered, starting with the first one
+    pub fn with_sample_every(mut self, every: usize) -> Self {
+        self.sample_every = Some(every.max(1));
+        self
+    }
+
+    // The subscriber is removed after it has been called this many times
+    pub fn with_max_calls(mut self, max_calls: usize) -> Self {
+        self.max_calls = Some(max_calls);
+        self
+    }
+
+    // Payloads the filter rejects never reach the callback
+    pub fn with_filter(self, filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.with_boxed_filter(Box::new(filter))
+    }
+
+    pub(crate) fn with_boxed_filter(mut self, filter: Box<SubscriberFilter<T>>) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    // Applied to payloads the filter accepted, right before they reach the callback
+    pub fn with_transform(self, transform: impl Fn(T) -> T + Send + Sync + 'static) -> Self {
+        self.with_boxed_transform(Box::new(transform))
+    }
+
+    pub(crate) fn with_boxed_transform(mut self, transform: Box<SubscriberTransform<T>>) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    // Called with every failed delivery before the event decides whether to remove the subscriber
+    pub fn with_on_error(
+        self,
+        on_error: impl Fn(&DispatchError<T, E>) + Send + Sync + 'static,
+    ) -> Self {
+        self.with_boxed_on_error(Box::new(on_error))
+    }
+
+    pub(crate) fn with_boxed_on_error(mut self, on_error: Box<SubscriberErrorHook<T, E>>) -> Self {
+        self.on_error = Some(on_error);
+        self
+    }
+
+    pub fn with_retry(mut self, retry: Retry) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    pub fn with_owner<O: Send + Sync + 'static>(self, owner: &Arc<O>) -> Self {
+        let owner: Weak<O> = Arc::downgrade(owner);
+        self.with_owner_weak(owner)
+    }
+
+    pub(crate) fn with_owner_weak(mut self, owner: Weak<dyn Any + Send + Sync>) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
     pub fn id(&self) -> u64 {
         self.id
     }
@@ -65,25 +448,491 @@ impl<T: Clone + Send> Subscriber<T> {
         self.remove_on_error
     }
 
-    //TODO: For closure callback, consider spawning a task to avoid blocking. Or defining a ClosureNonBlocking variant.
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_priority(&self, priority: i32) {
+        self.priority.store(priority, Ordering::Relaxed);
+    }
+
+    pub fn has_filter(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    pub fn is_dedup(&self) -> bool {
+        self.dedup.is_some()
+    }
+
+    // Buffered payloads and buffer size of channel subscribers
+    pub fn channel_usage(&self) -> Option<(usize, usize)> {
+        match &self.callback {
+            Callback::Channel(sender) => Some((
+                sender.max_capacity() - sender.capacity(),
+                sender.max_capacity(),
+            )),
+            _ => None,
+        }
+    }
+
+    // Returns how many congested sends happened in a row
+    pub(crate) fn record_send(&self, congested: bool) -> usize {
+        if !congested {
+            self.congested_sends.store(0, Ordering::Relaxed);
+            return 0;
+        }
+
+        self.congested_sends.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn sample_every(&self) -> Option<usize> {
+        self.sample_every
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.flow_state.load(Ordering::Acquire) == PAUSED
+    }
+
+    pub fn has_unsubscribed(&self) -> bool {
+        self.flow_state.load(Ordering::Acquire) == UNSUBSCRIBED
+    }
+
+    // Returns false if the subscriber was not paused
+    pub(crate) fn resume(&self) -> bool {
+        self.flow_state
+            .compare_exchange(PAUSED, ACTIVE, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+
+    pub(crate) fn pause(&self) -> bool {
+        self.flow_state
+            .compare_exchange(ACTIVE, PAUSED, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+
+    // A subscriber that has to be removed before the next dispatch
+    pub(crate) fn is_stale(&self) -> bool {
+        !self.is_owner_alive() || self.is_exhausted() || self.has_unsubscribed()
+    }
+
+    // Payloads the filter rejected don't count as the last payload for duplicate suppression,
+    // and sampling only counts payloads that got past both. Paused subscribers accept nothing.
+    pub fn accepts(&self, data: &T) -> bool {
+        if self.flow_state.load(Ordering::Acquire) != ACTIVE {
+            return false;
+        }
+
+        let filtered = match &self.filter {
+            Some(filter) => filter(data),
+            None => true,
+        };
+
+        let deduplicated = match &self.dedup {
+            Some(dedup) => filtered && dedup.accepts(data),
+            None => filtered,
+        };
+
+        match self.sample_every {
+            Some(every) => {
+                deduplicated
+                    && self
+                        .samples
+                        .fetch_add(1, Ordering::Relaxed)
+                        .is_multiple_of(every)
+            }
+            None => deduplicated,
+        }
+    }
+
+    pub fn has_transform(&self) -> bool {
+        self.transform.is_some()
+    }
+
+    pub fn retry(&self) -> Option<Retry> {
+        self.retry
+    }
+
+    pub fn has_on_error(&self) -> bool {
+        self.on_error.is_some()
+    }
+
+    pub(crate) fn notify_error(&self, err: &DispatchError<T, E>) {
+        if let Some(on_error) = &self.on_error {
+            on_error(err);
+        }
+    }
+
+    fn transform(&self, data: T) -> T {
+        match &self.transform {
+            Some(transform) => transform(data),
+            None => data,
+        }
+    }
+
+    pub fn max_calls(&self) -> Option<usize> {
+        self.max_calls
+    }
+
+    pub fn remaining_calls(&self) -> Option<usize> {
+        self.max_calls
+            .map(|max_calls| max_calls.saturating_sub(self.calls.load(Ordering::Acquire)))
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining_calls() == Some(0)
+    }
+
+    // Concurrent dispatches race for the remaining calls, so a call is claimed before it is made
+    pub(crate) fn try_claim_call(&self) -> bool {
+        let Some(max_calls) = self.max_calls else {
+            return true;
+        };
+
+        self.calls
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |calls| {
+                (calls < max_calls).then_some(calls + 1)
+            })
+            .is_ok()
+    }
+
+    // Callbacks that finish on the dispatching thread. Timeouts and retries need the runtime's timer.
+    #[cfg(feature = "sync")]
+    pub(crate) fn runs_without_runtime(&self) -> bool {
+        let callback = match &self.callback {
+            Callback::Channel(_)
+            | Callback::Closure(_)
+            | Callback::ClosureMut(_)
+            | Callback::FlowClosure(_) => true,
+            #[cfg(feature = "crossbeam")]
+            Callback::Crossbeam(_) => true,
+            _ => false,
+        };
+
+        callback && self.timeout.is_none() && self.retry.is_none()
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    pub fn has_owner(&self) -> bool {
+        self.owner.is_some()
+    }
+
+    pub fn is_owner_alive(&self) -> bool {
+        match &self.owner {
+            Some(owner) => owner.strong_count() > 0,
+            None => true,
+        }
+    }
+
+    // A closed channel, a dropped owner or used up calls mean the subscriber will never handle data again
+    pub fn is_alive(&self) -> bool {
+        let channel_open = match &self.callback {
+            Callback::Channel(sender) => !sender.is_closed(),
+            Callback::Custom(callback) => callback.is_alive(),
+            _ => true,
+        };
+
+        channel_open && !self.is_stale()
+    }
+
+    pub fn info(&self) -> SubscriberInfo {
+        SubscriberInfo {
+            id: self.id,
+            name: self.name.clone(),
+            group: self.group.clone(),
+            metadata: self.metadata.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority(),
+        }
+    }
+
     //TODO: Docs about cancelation safety. data can be dropped without reaching a channel.
-    pub async fn dispatch(&self, data: T) -> Result<(), DispatchError<T>> {
+    // Spawned and blocking callbacks run on the given runtime, usually the event's. Like filtered
+    // data, data beyond max_calls is dropped without an error.
+    pub async fn dispatch(
+        &self,
+        data: T,
+        runtime: &dyn Runtime,
+    ) -> Result<(), DispatchError<T, E>> {
+        if !self.accepts(&data) || !self.try_claim_call() {
+            return Ok(());
+        }
+
+        self.dispatch_on(data, runtime).await
+    }
+
+    pub(crate) async fn dispatch_on(
+        &self,
+        data: T,
+        runtime: &dyn Runtime,
+    ) -> Result<(), DispatchError<T, E>> {
+        let data = self.transform(data);
+        let Some(retry) = self.retry else {
+            return self.deliver(data, runtime).await;
+        };
+
+        // The last attempt gets the original data, earlier ones a clone
+        for delay in retry.delays() {
+            match self.deliver(data.clone(), runtime).await {
+                Err(err) if err.is_transient() => runtime.sleep(delay).await,
+                result => return result,
+            }
+        }
+
+        self.deliver(data, runtime).await
+    }
+
+    async fn deliver(&self, data: T, runtime: &dyn Runtime) -> Result<(), DispatchError<T, E>> {
         match &self.callback {
             Callback::Channel(sender) => {
                 sender.send(data).await.map_err(DispatchError::ChannelSend)
             }
             Callback::Closure(closure) => closure(data).map_err(DispatchError::Closure),
+            Callback::FlowClosure(closure) => closure(data)
+                .map(|flow| flow.apply(&self.flow_state))
+                .map_err(DispatchError::Closure),
+            Callback::AsyncFlowClosure(closure) => closure(data)
+                .await
+                .map(|flow| flow.apply(&self.flow_state))
+                .map_err(DispatchError::AsyncClosure),
+            Callback::ClosureMut(closure) => (closure.lock())(data).map_err(DispatchError::Closure),
             Callback::AsyncClosure(closure) => {
                 closure(data).await.map_err(DispatchError::AsyncClosure)
             }
+            Callback::Spawned(closure) => {
+                self.spawn_logged(closure(data), runtime);
+                Ok(())
+            }
+            Callback::BlockingClosure(closure) => {
+                let (task, receiver) = closure(data);
+                runtime
+                    .spawn_blocking(task)
+                    .await
+                    .map_err(DispatchError::BlockingTask)?;
+
+                match receiver.await {
+                    Ok(result) => result.map_err(DispatchError::BlockingClosure),
+                    Err(err) => Err(DispatchError::BlockingTask(BoxedError::from(err))),
+                }
+            }
+            Callback::Handler(handler) => {
+                handler.handle(data).await.map_err(DispatchError::Handler)
+            }
+            Callback::Custom(callback) => {
+                callback.deliver(data).await.map_err(DispatchError::Custom)
+            }
+            #[cfg(feature = "crossbeam")]
+            Callback::Crossbeam(sender) => sender.try_send(data).map_err(DispatchError::from),
+            #[cfg(feature = "futures")]
+            Callback::Sink(sink) => sink
+                .lock()
+                .await
+                .send(data)
+                .await
+                .map_err(DispatchError::Sink),
         }
     }
+
+    fn spawn_logged<R: Display>(
+        &self,
+        future: impl Future<Output = Result<(), R>> + Send + 'static,
+        runtime: &dyn Runtime,
+    ) {
+        let name = self.name.clone();
+        let log_on_error = self.log_on_error;
+
+        runtime.spawn(Box::pin(async move {
+            if let Err(err) = future.await
+                && log_on_error
+            {
+                error!("Spawned subscriber \"{}\" failed: {}.", name, err);
+            }
+        }));
+    }
+
+    // Must be called before the subscriber is published, finish_replay delivers the data
+    pub(crate) fn start_replay(&self, data: VecDeque<T>) {
+        *self.replay_backlog.lock() = Some(data);
+    }
+
+    // Hands the data back unless the subscriber is still replaying, in which case it is queued
+    pub(crate) fn defer_during_replay(&self, data: T) -> Option<T> {
+        match self.replay_backlog.lock().as_mut() {
+            Some(backlog) => {
+                backlog.push_back(data);
+                None
+            }
+            None => Some(data),
+        }
+    }
+
+    // Resolves once a channel subscriber's receiver took every queued payload, or was dropped.
+    // Holding all permits at once is only possible with an empty buffer.
+    pub async fn flush(&self) {
+        match &self.callback {
+            Callback::Channel(sender) => {
+                let _ = sender.reserve_many(sender.max_capacity()).await;
+            }
+            Callback::Custom(callback) => callback.flush().await,
+            #[cfg(feature = "futures")]
+            Callback::Sink(sink) => {
+                let _ = sink.lock().await.flush().await;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static, E: CallbackError> Subscriber<T, E> {
+    // Replays run outside of a dispatch, so failures can only be logged.
+    // Channels are filled without waiting and async closures are spawned on the event's runtime.
+    // Delivers the replayed data and everything dispatched meanwhile in order. No lock is held
+    // while the callback runs, so it may subscribe or dispatch to the event.
+    pub(crate) fn finish_replay(&self, runtime: &dyn Runtime) {
+        loop {
+            let data = {
+                let mut backlog = self.replay_backlog.lock();
+                match backlog.as_mut().and_then(VecDeque::pop_front) {
+                    Some(data) => data,
+                    None => {
+                        *backlog = None;
+                        return;
+                    }
+                }
+            };
+
+            self.replay(data, runtime);
+        }
+    }
+
+    pub(crate) fn replay(&self, data: T, runtime: &dyn Runtime) {
+        if !self.accepts(&data) || !self.try_claim_call() {
+            return;
+        }
+
+        let data = self.transform(data);
+        let result: Result<(), String> = match &self.callback {
+            Callback::Channel(sender) => sender.try_send(data).map_err(|err| err.to_string()),
+            Callback::Closure(closure) => closure(data).map_err(|err| err.to_string()),
+            Callback::FlowClosure(closure) => closure(data)
+                .map(|flow| flow.apply(&self.flow_state))
+                .map_err(|err| err.to_string()),
+            Callback::AsyncFlowClosure(closure) => {
+                let future = closure(data);
+                let flow_state = self.flow_state.clone();
+                self.spawn_logged(
+                    async move { future.await.map(|flow| flow.apply(&flow_state)) },
+                    runtime,
+                );
+                Ok(())
+            }
+            Callback::ClosureMut(closure) => (closure.lock())(data).map_err(|err| err.to_string()),
+            Callback::AsyncClosure(closure) | Callback::Spawned(closure) => {
+                self.spawn_logged(closure(data), runtime);
+                Ok(())
+            }
+            Callback::BlockingClosure(closure) => {
+                let (task, receiver) = closure(data);
+                let blocking = runtime.spawn_blocking(task);
+                self.spawn_logged(
+                    async move {
+                        blocking.await.map_err(|err| err.to_string())?;
+                        match receiver.await {
+                            Ok(result) => result.map_err(|err| err.to_string()),
+                            Err(err) => Err(err.to_string()),
+                        }
+                    },
+                    runtime,
+                );
+                Ok(())
+            }
+            // The spawned task keeps its own reference to the handler the future borrows
+            Callback::Handler(handler) => {
+                let handler = handler.clone();
+                self.spawn_logged(async move { handler.handle(data).await }, runtime);
+                Ok(())
+            }
+            Callback::Custom(callback) => match callback.try_deliver(data) {
+                Some(result) => result.map_err(|err| err.to_string()),
+                None => Err("The custom callback could not take the data right away".to_string()),
+            },
+            #[cfg(feature = "crossbeam")]
+            Callback::Crossbeam(sender) => sender.try_send(data).map_err(|err| err.to_string()),
+            // Like channels, sinks only get the data if they can take it right away
+            #[cfg(feature = "futures")]
+            Callback::Sink(sink) => match sink.try_lock() {
+                Ok(mut sink) => try_start_send(&mut sink, data),
+                Err(err) => Err(err.to_string()),
+            },
+        };
+
+        if let Err(err) = result
+            && self.log_on_error
+        {
+            error!(
+                "Failed to replay data to subscriber \"{}\": {}.",
+                self.name, err
+            );
+        }
+    }
+}
+
+impl<T: Clone + Send + PartialEq, E: CallbackError> Subscriber<T, E> {
+    // Skips payloads equal to the previously accepted one, for consumers that only care about changes
+    pub fn with_dedup(self) -> Self {
+        self.with_dedup_by(T::eq)
+    }
+}
+
+// The data is only handed over once the sink is ready, so it is either taken or never sent. A
+// flush that can't finish right away completes with the next send or Subscriber::flush.
+#[cfg(feature = "futures")]
+fn try_start_send<T, E: CallbackError>(
+    sink: &mut PinnedBoxedSink<T, E>,
+    data: T,
+) -> Result<(), String> {
+    let mut context = Context::from_waker(Waker::noop());
+    match sink.as_mut().poll_ready(&mut context) {
+        Poll::Ready(Ok(())) => {}
+        Poll::Ready(Err(err)) => return Err(err.to_string()),
+        Poll::Pending => return Err("The sink is not ready".to_string()),
+    }
+
+    sink.as_mut()
+        .start_send(data)
+        .map_err(|err| err.to_string())?;
+    match sink.as_mut().poll_flush(&mut context) {
+        Poll::Ready(Err(err)) => Err(err.to_string()),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn poll_once<F: Future>(future: F) -> Option<F::Output> {
+    let mut context = Context::from_waker(Waker::noop());
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => Some(output),
+        Poll::Pending => None,
+    }
 }
 
-impl<T: Clone + Send> PartialEq for Subscriber<T> {
+impl<T: Clone + Send, E: CallbackError> PartialEq for Subscriber<T, E> {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
 }
 
-impl<T: Clone + Send> Eq for Subscriber<T> {}
+impl<T: Clone + Send, E: CallbackError> Eq for Subscriber<T, E> {}
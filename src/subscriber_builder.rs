@@ -0,0 +1,504 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use lum_boxtypes::{BoxedError, PinnedBoxedFuture};
+use lum_libs::{
+    parking_lot::Mutex,
+    tokio::sync::mpsc::{Receiver, channel},
+};
+
+#[cfg(feature = "futures")]
+use futures_util::Sink;
+
+#[cfg(feature = "serde")]
+use crate::serialize_writer::{Framing, SerializeWriter, WriteError};
+#[cfg(feature = "serde")]
+use lum_libs::{serde::Serialize, tokio::io::AsyncWrite};
+
+use crate::{
+    CustomCallback, EventHandler, Subscriber,
+    retry::Retry,
+    subscriber::{
+        Callback, CallbackError, DispatchError, HandlerFlow, SubscriberErrorHook, SubscriberFilter,
+        SubscriberTransform,
+    },
+};
+
+pub struct SubscriberBuilder<T: Clone + Send, E: CallbackError = BoxedError> {
+    name: String,
+    log_on_error: bool,
+    remove_on_error: bool,
+    group: Option<String>,
+    timeout: Option<Duration>,
+    metadata: BTreeMap<String, String>,
+    labels: BTreeMap<String, String>,
+    max_calls: Option<usize>,
+    priority: i32,
+    dedup: Option<fn(&T, &T) -> bool>,
+    sample_every: Option<usize>,
+    filter: Option<Box<SubscriberFilter<T>>>,
+    transform: Option<Box<SubscriberTransform<T>>>,
+    on_error: Option<Box<SubscriberErrorHook<T, E>>>,
+    retry: Option<Retry>,
+    owner: Option<Weak<dyn Any + Send + Sync>>,
+    _data: PhantomData<fn() -> (T, E)>,
+}
+
+impl<T: Clone + Send, E: CallbackError> SubscriberBuilder<T, E> {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            log_on_error: true,
+            remove_on_error: false,
+            group: None,
+            timeout: None,
+            metadata: BTreeMap::new(),
+            labels: BTreeMap::new(),
+            max_calls: None,
+            priority: 0,
+            dedup: None,
+            sample_every: None,
+            filter: None,
+            transform: None,
+            on_error: None,
+            retry: None,
+            owner: None,
+            _data: PhantomData,
+        }
+    }
+
+    pub fn log_on_error(mut self, log_on_error: bool) -> Self {
+        self.log_on_error = log_on_error;
+        self
+    }
+
+    pub fn remove_on_error(mut self, remove_on_error: bool) -> Self {
+        self.remove_on_error = remove_on_error;
+        self
+    }
+
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    // Exceeding the timeout fails the delivery with DispatchError::Timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn dedup(mut self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.dedup = Some(T::eq);
+        self
+    }
+
+    pub fn sample_every(mut self, every: usize) -> Self {
+        self.sample_every = Some(every);
+        self
+    }
+
+    pub fn max_calls(mut self, max_calls: usize) -> Self {
+        self.max_calls = Some(max_calls);
+        self
+    }
+
+    pub fn filter(mut self, filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    pub fn transform(mut self, transform: impl Fn(T) -> T + Send + Sync + 'static) -> Self {
+        self.transform = Some(Box::new(transform));
+        self
+    }
+
+    pub fn on_error(
+        mut self,
+        on_error: impl Fn(&DispatchError<T, E>) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_error = Some(Box::new(on_error));
+        self
+    }
+
+    pub fn retry(mut self, retry: Retry) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    pub fn owner<O: Send + Sync + 'static>(mut self, owner: &Arc<O>) -> Self {
+        let owner: Weak<O> = Arc::downgrade(owner);
+        self.owner = Some(owner);
+        self
+    }
+
+    pub fn callback(self, callback: Callback<T, E>) -> Subscriber<T, E> {
+        let mut subscriber =
+            Subscriber::new(self.name, self.log_on_error, self.remove_on_error, callback);
+
+        if let Some(group) = self.group {
+            subscriber = subscriber.with_group(group);
+        }
+
+        if let Some(timeout) = self.timeout {
+            subscriber = subscriber.with_timeout(timeout);
+        }
+
+        for (key, value) in self.metadata {
+            subscriber = subscriber.with_metadata(key, value);
+        }
+
+        for (key, value) in self.labels {
+            subscriber = subscriber.with_label(key, value);
+        }
+
+        subscriber = subscriber.with_priority(self.priority);
+
+        if let Some(eq) = self.dedup {
+            subscriber = subscriber.with_dedup_by(eq);
+        }
+
+        if let Some(every) = self.sample_every {
+            subscriber = subscriber.with_sample_every(every);
+        }
+
+        if let Some(max_calls) = self.max_calls {
+            subscriber = subscriber.with_max_calls(max_calls);
+        }
+
+        if let Some(filter) = self.filter {
+            subscriber = subscriber.with_boxed_filter(filter);
+        }
+
+        if let Some(transform) = self.transform {
+            subscriber = subscriber.with_boxed_transform(transform);
+        }
+
+        if let Some(on_error) = self.on_error {
+            subscriber = subscriber.with_boxed_on_error(on_error);
+        }
+
+        if let Some(retry) = self.retry {
+            subscriber = subscriber.with_retry(retry);
+        }
+
+        if let Some(owner) = self.owner {
+            subscriber = subscriber.with_owner_weak(owner);
+        }
+
+        subscriber
+    }
+
+    pub fn channel(self, buffer: usize) -> (Subscriber<T, E>, Receiver<T>) {
+        let (sender, receiver) = channel(buffer);
+        (self.callback(Callback::Channel(sender)), receiver)
+    }
+
+    pub fn closure(
+        self,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::Closure(Box::new(closure)))
+    }
+
+    pub fn closure_mut(
+        self,
+        closure: impl FnMut(T) -> Result<(), E> + Send + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::ClosureMut(Mutex::new(Box::new(closure))))
+    }
+
+    pub fn async_closure(
+        self,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::AsyncClosure(Box::new(closure)))
+    }
+
+    pub fn flow_closure(
+        self,
+        closure: impl Fn(T) -> Result<HandlerFlow, E> + Send + Sync + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::FlowClosure(Box::new(closure)))
+    }
+
+    pub fn async_flow_closure(
+        self,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<HandlerFlow, E>> + Send + Sync + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::AsyncFlowClosure(Box::new(closure)))
+    }
+
+    pub fn blocking_closure(
+        self,
+        closure: impl Fn(T) -> Result<(), E> + Send + Sync + 'static,
+    ) -> Subscriber<T, E>
+    where
+        T: 'static,
+    {
+        self.callback(Callback::blocking(closure))
+    }
+
+    pub fn handler<H: EventHandler<T, E> + 'static>(self, handler: Arc<H>) -> Subscriber<T, E> {
+        self.callback(Callback::Handler(handler))
+    }
+
+    pub fn custom(self, callback: impl CustomCallback<T, E> + 'static) -> Subscriber<T, E> {
+        self.callback(Callback::Custom(Box::new(callback)))
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn writer<W: AsyncWrite + Unpin + Send + 'static>(
+        self,
+        writer: W,
+        framing: Framing,
+    ) -> Subscriber<T, E>
+    where
+        T: Serialize,
+        E: From<WriteError>,
+    {
+        self.custom(SerializeWriter::new(writer, framing))
+    }
+
+    #[cfg(feature = "crossbeam")]
+    pub fn crossbeam(self, sender: crossbeam_channel::Sender<T>) -> Subscriber<T, E> {
+        self.callback(Callback::Crossbeam(sender))
+    }
+
+    #[cfg(feature = "futures")]
+    pub fn sink<SE: Into<E> + 'static>(
+        self,
+        sink: impl Sink<T, Error = SE> + Send + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::sink(sink))
+    }
+
+    pub fn spawned_closure(
+        self,
+        closure: impl Fn(T) -> PinnedBoxedFuture<Result<(), E>> + Send + Sync + 'static,
+    ) -> Subscriber<T, E> {
+        self.callback(Callback::Spawned(Box::new(closure)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Event, runtime::TokioRuntime};
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+    const GROUP: &str = "test_group";
+
+    #[tokio::test]
+    async fn build_channel_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (subscriber, mut receiver) = Subscriber::builder(SUBSCRIBER_NAME)
+            .log_on_error(false)
+            .remove_on_error(true)
+            .group(GROUP)
+            .channel(1);
+
+        assert_eq!(subscriber.name(), SUBSCRIBER_NAME);
+        assert_eq!(subscriber.group(), Some(GROUP));
+        assert!(!subscriber.log_on_error());
+        assert!(subscriber.remove_on_error());
+
+        event.subscribe(subscriber).unwrap();
+        event.dispatch(1).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(1));
+
+        drop(receiver);
+        assert!(event.dispatch(2).await.is_err());
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn build_owned_closure_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let owner = Arc::new(());
+        let subscriber = Subscriber::builder(SUBSCRIBER_NAME)
+            .owner(&owner)
+            .closure(|_data| Ok(()));
+        assert!(subscriber.has_owner());
+
+        event.subscribe(subscriber).unwrap();
+        drop(owner);
+        event.dispatch(1).await.unwrap();
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn pass_metadata_to_error_handler() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let subscriber = Subscriber::builder(SUBSCRIBER_NAME)
+            .log_on_error(false)
+            .metadata("module", "images")
+            .metadata("version", "1.2.0")
+            .closure(|_data| Err("failed".into()));
+        assert_eq!(subscriber.metadata().get("module").unwrap(), "images");
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        event.set_error_handler(move |_error, subscriber| {
+            let _ = sender.send(subscriber.metadata.clone());
+        });
+
+        event.subscribe(subscriber).unwrap();
+        assert!(event.dispatch(1).await.is_err());
+
+        let metadata = receiver.recv().await.unwrap();
+        assert_eq!(metadata.get("version").map(String::as_str), Some("1.2.0"));
+        assert_eq!(metadata.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn remove_after_max_calls() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (subscriber, mut receiver) = Subscriber::builder(SUBSCRIBER_NAME)
+            .max_calls(2)
+            .channel(10);
+        assert_eq!(subscriber.remaining_calls(), Some(2));
+        event.subscribe(subscriber).unwrap();
+
+        for data in 1..=3 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        assert_eq!(event.subscriber_count(), 0);
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn limit_direct_dispatch_to_max_calls() {
+        let (subscriber, mut receiver) = Subscriber::<u8>::builder(SUBSCRIBER_NAME)
+            .max_calls(2)
+            .channel(10);
+
+        for data in 1..=3 {
+            subscriber.dispatch(data, &TokioRuntime).await.unwrap();
+        }
+
+        assert!(subscriber.is_exhausted());
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn filter_channel_subscriber() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (subscriber, mut receiver) = Subscriber::builder(SUBSCRIBER_NAME)
+            .filter(|data: &u8| data.is_multiple_of(2))
+            .max_calls(2)
+            .channel(10);
+        event.subscribe(subscriber).unwrap();
+
+        for data in 1..=4 {
+            let report = event.dispatch_report(data).await;
+            assert!(report.is_success());
+            assert_eq!(report.filtered_count(), (data % 2) as usize);
+        }
+
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(receiver.recv().await, Some(4));
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn transform_before_delivery() {
+        let event = Event::<String>::new(EVENT_NAME);
+        let (redacted, mut redacted_receiver) = Subscriber::builder(SUBSCRIBER_NAME)
+            .transform(|data: String| data.replace("secret", "***"))
+            .channel(10);
+        let (raw, mut raw_receiver) = Subscriber::builder(SUBSCRIBER_NAME).channel(10);
+        event.subscribe(redacted).unwrap();
+        event.subscribe(raw).unwrap();
+
+        event.dispatch("user secret".to_string()).await.unwrap();
+
+        assert_eq!(redacted_receiver.recv().await.unwrap(), "user ***");
+        assert_eq!(raw_receiver.recv().await.unwrap(), "user secret");
+    }
+
+    #[tokio::test]
+    async fn skip_consecutive_duplicates() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (subscriber, mut receiver) = Subscriber::builder(SUBSCRIBER_NAME).dedup().channel(10);
+        event.subscribe(subscriber).unwrap();
+
+        for data in [1, 1, 2, 2, 2, 1] {
+            event.dispatch(data).await.unwrap();
+        }
+
+        for expected in [1, 2, 1] {
+            assert_eq!(receiver.recv().await, Some(expected));
+        }
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn deliver_every_nth_payload() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (subscriber, mut receiver) = Subscriber::builder(SUBSCRIBER_NAME)
+            .sample_every(3)
+            .channel(10);
+        assert_eq!(subscriber.sample_every(), Some(3));
+        event.subscribe(subscriber).unwrap();
+
+        for data in 1..=7 {
+            event.dispatch(data).await.unwrap();
+        }
+
+        for expected in [1, 4, 7] {
+            assert_eq!(receiver.recv().await, Some(expected));
+        }
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn call_on_error_before_removal() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let subscriber = Subscriber::builder(SUBSCRIBER_NAME)
+            .log_on_error(false)
+            .remove_on_error(true)
+            .on_error(move |error| {
+                let _ = sender.send(error.to_string());
+            })
+            .closure(|_data| Err("connection lost".into()));
+        assert!(subscriber.has_on_error());
+
+        event.subscribe(subscriber).unwrap();
+        assert!(event.dispatch(1).await.is_err());
+
+        let error = receiver.recv().await.unwrap();
+        assert!(error.contains("connection lost"));
+        assert_eq!(event.subscriber_count(), 0);
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{self, Debug, Formatter};
+
+use lum_boxtypes::BoxedError;
+
+use crate::{event::EventHandle, subscriber::CallbackError};
+
+#[must_use = "dropping a SubscriptionGuard immediately unsubscribes the subscriber"]
+pub struct SubscriptionGuard<T: Clone + Send, E: CallbackError = BoxedError> {
+    event_handle: EventHandle<T, E>,
+    subscriber_id: u64,
+    active: bool,
+}
+
+impl<T: Clone + Send, E: CallbackError> SubscriptionGuard<T, E> {
+    pub fn new(event_handle: impl Into<EventHandle<T, E>>, subscriber_id: u64) -> Self {
+        Self {
+            event_handle: event_handle.into(),
+            subscriber_id,
+            active: true,
+        }
+    }
+
+    pub fn subscriber_id(&self) -> u64 {
+        self.subscriber_id
+    }
+
+    pub fn event_handle(&self) -> &EventHandle<T, E> {
+        &self.event_handle
+    }
+
+    // Keeps the subscription alive without the guard
+    pub fn detach(mut self) -> u64 {
+        self.active = false;
+        self.subscriber_id
+    }
+
+    // Returns false if the subscriber or the event were already gone
+    pub fn unsubscribe(mut self) -> bool {
+        self.active = false;
+        self.event_handle
+            .unsubscribe(self.subscriber_id)
+            .unwrap_or(false)
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Drop for SubscriptionGuard<T, E> {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = self.event_handle.unsubscribe(self.subscriber_id);
+        }
+    }
+}
+
+impl<T: Clone + Send, E: CallbackError> Debug for SubscriptionGuard<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubscriptionGuard")
+            .field("event", &self.event_handle)
+            .field("subscriber_id", &self.subscriber_id)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Event;
+    use lum_libs::tokio;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[tokio::test]
+    async fn unsubscribe_on_drop() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let guard = event
+            .subscribe_closure_guarded(SUBSCRIBER_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        assert_eq!(event.subscriber_count(), 1);
+
+        drop(guard);
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_channel_on_drop() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        {
+            let (_guard, _receiver) = event
+                .subscribe_channel_guarded(SUBSCRIBER_NAME, 1, false, false)
+                .unwrap();
+            let _async_guard = event
+                .subscribe_async_closure_guarded(
+                    SUBSCRIBER_NAME,
+                    |_data| Box::pin(async { Ok(()) }),
+                    false,
+                    false,
+                )
+                .unwrap();
+            assert_eq!(event.subscriber_count(), 2);
+        }
+
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn detach_keeps_subscription() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let guard = event
+            .subscribe_closure_guarded(SUBSCRIBER_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        let id = guard.detach();
+        assert_eq!(event.subscriber_count(), 1);
+        assert!(event.unsubscribe(id));
+    }
+
+    #[tokio::test]
+    async fn explicit_unsubscribe() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let guard = event
+            .subscribe_closure_guarded(SUBSCRIBER_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        let id = guard.subscriber_id();
+        assert!(event.unsubscribe(id));
+
+        let guard = SubscriptionGuard::new(&event, id);
+        assert!(!guard.unsubscribe());
+    }
+
+    #[tokio::test]
+    async fn drop_after_event_dropped() {
+        let event = Event::<u8>::new(EVENT_NAME);
+        let guard = event
+            .subscribe_closure_guarded(SUBSCRIBER_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+
+        drop(event);
+        drop(guard);
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    any::type_name,
+    fmt::{self, Debug, Display, Formatter},
+    pin::pin,
+    sync::{
+        Arc,
+        mpsc::{self, Receiver},
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+use lum_boxtypes::BoxedError;
+use lum_libs::parking_lot::Mutex;
+
+use crate::{
+    Event,
+    dispatch_report::DispatchReport,
+    event::SubscribeError,
+    subscriber::{Callback, DispatchFailure, HandlerFlow, Subscriber},
+};
+
+// Same subscribers and dispatch as Event, but callbacks run on the dispatching thread.
+// Only subscribers that finish without a runtime are accepted.
+pub struct SyncEvent<T: Clone + Send + 'static> {
+    event: Event<T>,
+}
+
+impl<T: Clone + Send + 'static> SyncEvent<T> {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            event: Event::new(name),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.event.id()
+    }
+
+    pub fn name(&self) -> &str {
+        self.event.name()
+    }
+
+    pub fn subscriber_count(&self) -> usize {
+        self.event.subscriber_count()
+    }
+
+    pub fn subscribe(&self, subscriber: Subscriber<T>) -> Result<u64, SubscribeError> {
+        if !subscriber.runs_without_runtime() {
+            return Err(SubscribeError::NeedsRuntime {
+                event_name: self.name().to_string(),
+                subscriber_name: subscriber.name().to_string(),
+            });
+        }
+
+        self.event.subscribe(subscriber)
+    }
+
+    pub fn subscribe_channel(
+        &self,
+        name: impl Into<String>,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<(u64, Receiver<T>), SubscribeError> {
+        let (sender, receiver) = mpsc::channel();
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Closure(Box::new(move |data| {
+                sender
+                    .send(data)
+                    .map_err(|err| BoxedError::from(err.to_string()))
+            })),
+        );
+
+        Ok((self.subscribe(subscriber)?, receiver))
+    }
+
+    pub fn subscribe_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<(), BoxedError> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::Closure(Box::new(closure)),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn subscribe_closure_mut(
+        &self,
+        name: impl Into<String>,
+        closure: impl FnMut(T) -> Result<(), BoxedError> + Send + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::ClosureMut(Mutex::new(Box::new(closure))),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn subscribe_flow_closure(
+        &self,
+        name: impl Into<String>,
+        closure: impl Fn(T) -> Result<HandlerFlow, BoxedError> + Send + Sync + 'static,
+        log_on_error: bool,
+        remove_on_error: bool,
+    ) -> Result<u64, SubscribeError> {
+        let subscriber = Subscriber::new(
+            name,
+            log_on_error,
+            remove_on_error,
+            Callback::FlowClosure(Box::new(closure)),
+        );
+
+        self.subscribe(subscriber)
+    }
+
+    pub fn unsubscribe(&self, id: u64) -> bool {
+        self.event.unsubscribe(id)
+    }
+
+    pub fn dispatch(&self, data: T) -> Result<(), Vec<DispatchFailure<T>>> {
+        self.dispatch_report(data).into_result()
+    }
+
+    pub fn dispatch_report(&self, data: T) -> DispatchReport<T> {
+        block_on(self.event.dispatch_report(data))
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Accepted subscribers finish right away, except for a full tokio channel. That parks the
+// dispatching thread until the receiver makes room.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+
+        thread::park();
+    }
+}
+
+impl<T: Clone + Send + 'static> Debug for SyncEvent<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("id", &self.id())
+            .field("name", &self.name())
+            .field("subscribers", &self.subscriber_count())
+            .finish()
+    }
+}
+
+impl<T: Clone + Send + 'static> Display for SyncEvent<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let sub_count = self.subscriber_count();
+        let sub_word = if sub_count == 1 {
+            "subscriber"
+        } else {
+            "subscribers"
+        };
+
+        write!(f, "SyncEvent {} ({} {})", self.name(), sub_count, sub_word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscriber::DispatchError;
+
+    const EVENT_NAME: &str = "test_event";
+    const SUBSCRIBER_NAME: &str = "test_subscriber";
+
+    #[test]
+    fn dispatch_without_runtime() {
+        let event = SyncEvent::<u8>::new(EVENT_NAME);
+        let (_, receiver) = event
+            .subscribe_channel(SUBSCRIBER_NAME, false, false)
+            .unwrap();
+
+        let mut received = Vec::new();
+        let (sender, sum_receiver) = mpsc::channel();
+        event
+            .subscribe_closure_mut(
+                SUBSCRIBER_NAME,
+                move |data| {
+                    received.push(data);
+                    sender.send(received.len())?;
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        event.dispatch(1).unwrap();
+        event.dispatch(2).unwrap();
+
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(sum_receiver.try_iter().last(), Some(2));
+    }
+
+    #[test]
+    fn remove_failing_subscriber() {
+        let event = SyncEvent::<u8>::new(EVENT_NAME);
+        let (id, receiver) = event
+            .subscribe_channel(SUBSCRIBER_NAME, false, true)
+            .unwrap();
+        event
+            .subscribe_closure(SUBSCRIBER_NAME, |_data| Ok(()), false, false)
+            .unwrap();
+        drop(receiver);
+
+        let errors = event.dispatch(1).unwrap_err();
+        assert!(matches!(errors[0].error, DispatchError::Closure(_)));
+        assert_eq!(event.subscriber_count(), 1);
+        assert!(!event.unsubscribe(id));
+    }
+
+    #[test]
+    fn reject_subscriber_that_needs_runtime() {
+        let event = SyncEvent::<u8>::new(EVENT_NAME);
+        let subscriber = Subscriber::new(
+            SUBSCRIBER_NAME,
+            false,
+            false,
+            Callback::AsyncClosure(Box::new(|_data| Box::pin(async { Ok(()) }))),
+        );
+
+        assert!(matches!(
+            event.subscribe(subscriber),
+            Err(SubscribeError::NeedsRuntime { .. })
+        ));
+    }
+}
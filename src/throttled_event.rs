@@ -0,0 +1,192 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+
+use lum_libs::tokio::{sync::mpsc::UnboundedReceiver, time::Instant};
+
+use crate::{
+    Event,
+    event::EventHandle,
+    relay::{Relay, RelayError},
+    runtime::{Runtime, TokioRuntime, timeout_at},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleMode {
+    Drop,
+    Conflate,
+}
+
+pub struct ThrottledEvent<T: Clone + Send + 'static> {
+    pub event: Event<T>,
+
+    interval: Duration,
+    mode: ThrottleMode,
+    relay: Relay<T>,
+}
+
+impl<T: Clone + Send + 'static> ThrottledEvent<T> {
+    pub fn new(name: impl Into<String>, interval: Duration, mode: ThrottleMode) -> Self {
+        Self::new_with_runtime(name, interval, mode, TokioRuntime)
+    }
+
+    pub fn new_with_runtime(
+        name: impl Into<String>,
+        interval: Duration,
+        mode: ThrottleMode,
+        runtime: impl Runtime,
+    ) -> Self {
+        let event = Event::builder(name).runtime(runtime).build();
+        let relay = Relay::spawn(
+            &event,
+            move |runtime, event_handle, event_queue_receiver| {
+                run_throttle_loop(runtime, event_handle, event_queue_receiver, interval, mode)
+            },
+        );
+
+        Self {
+            event,
+            interval,
+            mode,
+            relay,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.event.name()
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub fn mode(&self) -> ThrottleMode {
+        self.mode
+    }
+
+    pub fn dispatch(&self, data: T) -> Result<(), RelayError> {
+        self.relay.send(&self.event, data)
+    }
+}
+
+async fn run_throttle_loop<T: Clone + Send + 'static>(
+    runtime: Arc<dyn Runtime>,
+    event_handle: EventHandle<T>,
+    mut event_queue_receiver: UnboundedReceiver<T>,
+    interval: Duration,
+    mode: ThrottleMode,
+) {
+    while let Some(data) = event_queue_receiver.recv().await {
+        if event_handle.dispatch(data).await.is_err() {
+            return;
+        }
+
+        let mut interval_end = Instant::now() + interval;
+        loop {
+            let mut latest = None;
+            let mut queue_closed = false;
+
+            loop {
+                match timeout_at(&*runtime, interval_end, event_queue_receiver.recv()).await {
+                    Some(Some(next)) => {
+                        if mode == ThrottleMode::Conflate {
+                            latest = Some(next);
+                        }
+                    }
+                    Some(None) => {
+                        queue_closed = true;
+                        break;
+                    }
+                    None => break,
+                }
+            }
+
+            // A conflated value is delivered at the end of the interval and starts the next one
+            let Some(latest) = latest else {
+                if queue_closed {
+                    return;
+                }
+
+                break;
+            };
+
+            if event_handle.dispatch(latest).await.is_err() || queue_closed {
+                return;
+            }
+
+            interval_end = Instant::now() + interval;
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> AsRef<Event<T>> for ThrottledEvent<T> {
+    fn as_ref(&self) -> &Event<T> {
+        &self.event
+    }
+}
+
+impl<T: Clone + Send + 'static> Display for ThrottledEvent<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ThrottledEvent {} ({}ms interval)",
+            self.event.name(),
+            self.interval.as_millis()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lum_libs::tokio::{self, time::sleep};
+
+    const EVENT_NAME: &str = "test_throttled_event";
+    const RECEIVER_NAME: &str = "test_receiver";
+    const INTERVAL: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn drop_intermediate_values() {
+        let throttled_event = ThrottledEvent::new(EVENT_NAME, INTERVAL, ThrottleMode::Drop);
+        let (_, mut receiver) = throttled_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        for i in 0..10u32 {
+            throttled_event.dispatch(i).unwrap();
+        }
+
+        assert_eq!(receiver.recv().await.unwrap(), 0);
+
+        sleep(INTERVAL * 2).await;
+        assert!(receiver.try_recv().is_err());
+
+        throttled_event.dispatch(10).unwrap();
+        assert_eq!(receiver.recv().await.unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn conflate_intermediate_values() {
+        let throttled_event = ThrottledEvent::new(EVENT_NAME, INTERVAL, ThrottleMode::Conflate);
+        let (_, mut receiver) = throttled_event
+            .event
+            .subscribe_channel(RECEIVER_NAME, 10, false, false)
+            .unwrap();
+
+        for i in 0..10u32 {
+            throttled_event.dispatch(i).unwrap();
+        }
+
+        assert_eq!(receiver.recv().await.unwrap(), 0);
+
+        let start = Instant::now();
+        assert_eq!(receiver.recv().await.unwrap(), 9);
+        assert!(start.elapsed() >= INTERVAL / 2);
+
+        sleep(INTERVAL * 2).await;
+        assert!(receiver.try_recv().is_err());
+    }
+}
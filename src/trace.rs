@@ -0,0 +1,16 @@
+// Wraps a future in a tracing span when the `tracing` feature is enabled, otherwise passes it through
+macro_rules! traced {
+    ($future:expr, $($span:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        {
+            ::tracing::Instrument::instrument($future, ::tracing::debug_span!($($span)+))
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        {
+            $future
+        }
+    }};
+}
+
+pub(crate) use traced;
@@ -31,7 +31,9 @@ mod tests {
     #[tokio::test]
     async fn event_subscribe_channel() {
         let event = Event::new(TEST_EVENT_NAME);
-        let (_, mut receiver) = event.subscribe_channel(TEST_CHANNEL_NAME, 10, false, false);
+        let (_, mut receiver) = event
+            .subscribe_channel(TEST_CHANNEL_NAME, 10, false, false)
+            .unwrap();
 
         event.dispatch(TEST_DATA.to_string()).await.unwrap();
         let result = receiver.recv().await.unwrap();
@@ -44,17 +46,19 @@ mod tests {
     async fn event_subscribe_async_closure() {
         let event = Event::new(TEST_EVENT_NAME);
 
-        event.subscribe_async_closure(
-            TEST_ASYNC_CLOSURE_NAME,
-            move |data| {
-                Box::pin(async move {
-                    assert_eq!(data, TEST_DATA.to_string());
-                    Ok(())
-                })
-            },
-            false,
-            false,
-        );
+        event
+            .subscribe_async_closure(
+                TEST_ASYNC_CLOSURE_NAME,
+                move |data| {
+                    Box::pin(async move {
+                        assert_eq!(data, TEST_DATA.to_string());
+                        Ok(())
+                    })
+                },
+                false,
+                false,
+            )
+            .unwrap();
 
         event.dispatch(TEST_DATA.to_string()).await.unwrap();
 
@@ -65,15 +69,17 @@ mod tests {
     async fn event_subscribe_closure() {
         let event = Event::new(TEST_EVENT_NAME);
 
-        event.subscribe_closure(
-            TEST_CLOSURE_NAME,
-            move |data| {
-                assert_eq!(data, TEST_DATA.to_string());
-                Ok(())
-            },
-            false,
-            false,
-        );
+        event
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |data| {
+                    assert_eq!(data, TEST_DATA.to_string());
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
 
         event.dispatch(TEST_DATA.to_string()).await.unwrap();
 
@@ -86,15 +92,17 @@ mod tests {
         let count = Arc::new(AtomicU8::new(0));
 
         let count_clone = count.clone();
-        let id = event.subscribe_closure(
-            TEST_CLOSURE_NAME,
-            move |_data| {
-                count_clone.fetch_add(1, Ordering::Relaxed);
-                Ok(())
-            },
-            false,
-            false,
-        );
+        let id = event
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |_data| {
+                    count_clone.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
 
         assert_eq!(event.subscriber_count(), 1);
         assert_eq!(count.load(Ordering::Relaxed), 0);
@@ -116,12 +124,14 @@ mod tests {
     #[tokio::test]
     async fn event_dispatch_with_error() {
         let event = Event::new(TEST_EVENT_NAME);
-        event.subscribe_closure(
-            TEST_CLOSURE_NAME,
-            |_data| Err(Box::new(io::Error::other(TEST_ERROR))),
-            true,
-            true,
-        );
+        event
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                |_data| Err(Box::new(io::Error::other(TEST_ERROR))),
+                true,
+                true,
+            )
+            .unwrap();
         assert_eq!(event.subscriber_count(), 1);
 
         let result = event.dispatch(TEST_DATA.to_string()).await;
@@ -146,11 +156,11 @@ mod tests {
         let display_str = format!("{event}");
         assert_eq!(display_str, "Event test_event (0 subscribers)");
 
-        let subscriber1 = event.subscribe_channel("Test", 100, false, false);
+        let subscriber1 = event.subscribe_channel("Test", 100, false, false).unwrap();
         let display_str = format!("{event}");
         assert_eq!(display_str, "Event test_event (1 subscriber)");
 
-        let subscriber2 = event.subscribe_channel("Test2", 100, false, false);
+        let subscriber2 = event.subscribe_channel("Test2", 100, false, false).unwrap();
         let display_str = format!("{event}");
         assert_eq!(display_str, "Event test_event (2 subscribers)");
 
@@ -1,12 +1,15 @@
 #[cfg(test)]
 mod tests {
 
-    use std::sync::{
-        Arc,
-        atomic::{AtomicU8, Ordering},
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, AtomicU8, Ordering},
+        },
+        time::Duration,
     };
 
-    use lum_event::{ArcObservable, Observable};
+    use lum_event::{ArcObservable, Event, Observable, observable, observable::ChangeDetection};
     use lum_libs::tokio::{self};
 
     static TEST_EVENT_NAME: &str = "test_event";
@@ -20,30 +23,35 @@ mod tests {
         let observable = Observable::new(TEST_DATA, TEST_EVENT_NAME);
         let data = observable.get();
         let data_as_ref: &str = observable.as_ref();
+        let data_with: &str = observable.with(|data| *data);
 
         assert_eq!(observable, TEST_DATA);
         assert_eq!(data, TEST_DATA);
         assert_eq!(data_as_ref, TEST_DATA);
+        assert_eq!(data_with, TEST_DATA);
     }
 
     //TODO: This is an integration test that stretches across multiple components. Move to event_system.rs
     //TODO: Use sender/receiver instead?
     #[tokio::test]
     async fn observable_subscriber_receives_data_on_change() {
-        let mut observable = Observable::new(TEST_DATA_INITIAL, TEST_EVENT_NAME);
+        let observable = Observable::new(TEST_DATA_INITIAL, TEST_EVENT_NAME);
         let count = Arc::new(AtomicU8::new(0));
 
         let count_clone = count.clone();
-        let id = observable.on_change.subscribe_closure(
-            TEST_CLOSURE_NAME,
-            move |data| {
-                assert_eq!(data, TEST_DATA);
-                count_clone.fetch_add(1, Ordering::Relaxed);
-                Ok(())
-            },
-            false,
-            false,
-        );
+        let id = observable
+            .on_change
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |data| {
+                    assert_eq!(data, TEST_DATA);
+                    count_clone.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
         assert_eq!(observable.on_change.subscriber_count(), 1);
         assert_eq!(count.load(Ordering::Relaxed), 0);
 
@@ -60,6 +68,1008 @@ mod tests {
         assert_eq!(count.load(Ordering::Relaxed), 1);
     }
 
+    #[tokio::test]
+    async fn observable_transition_carries_old_and_new_value() {
+        let observable = Observable::new(TEST_DATA_INITIAL, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_transition
+            .subscribe_channel(TEST_CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        observable.set(TEST_DATA).await;
+
+        let change = receiver.recv().await.unwrap();
+        assert_eq!(change.old, TEST_DATA_INITIAL);
+        assert_eq!(change.new, TEST_DATA);
+    }
+
+    #[tokio::test]
+    async fn observable_compare_and_set() {
+        let observable = Observable::new(TEST_DATA_INITIAL, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        assert!(
+            observable
+                .compare_and_set(&TEST_DATA, TEST_DATA)
+                .await
+                .is_none()
+        );
+        assert!(receiver.try_recv().is_err());
+
+        let result = observable
+            .compare_and_set(&TEST_DATA_INITIAL, TEST_DATA)
+            .await;
+        assert!(matches!(result, Some(observable::Result::Changed(Ok(())))));
+        assert_eq!(receiver.recv().await, Some(TEST_DATA));
+    }
+
+    #[tokio::test]
+    async fn observable_update_in_place() {
+        let observable = Observable::new(vec![1], TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        observable.update(|values| values.push(2)).await;
+        assert_eq!(receiver.recv().await, Some(vec![1, 2]));
+
+        let result = observable.update_if_changed(|values| values.sort()).await;
+        assert!(matches!(result, observable::Result::Unchanged));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn observable_replace_returns_previous_value() {
+        let observable = Observable::new(TEST_DATA_INITIAL, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        let (old, result) = observable.replace(TEST_DATA).await;
+        assert_eq!(old, TEST_DATA_INITIAL);
+        assert!(matches!(result, observable::Result::Changed(Ok(()))));
+        assert_eq!(observable.get(), TEST_DATA);
+        assert_eq!(receiver.recv().await, Some(TEST_DATA));
+    }
+
+    #[tokio::test]
+    async fn observable_replace_hands_back_rejected_value() {
+        let observable = Observable::new(1u8, TEST_EVENT_NAME);
+        observable.set_validator(|value| match value {
+            0..=9 => Ok(()),
+            _ => Err("Too large".into()),
+        });
+
+        let (rejected, result) = observable.replace(10).await;
+        assert_eq!(rejected, 10);
+        assert!(matches!(result, observable::Result::Rejected(_)));
+        assert_eq!(observable.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn observable_force_set_dispatches_same_value() {
+        let observable = Observable::new(TEST_DATA, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 1, false, false)
+            .unwrap();
+
+        let result = observable.force_set(TEST_DATA).await;
+        assert!(matches!(result, observable::Result::Changed(Ok(()))));
+        assert_eq!(receiver.recv().await, Some(TEST_DATA));
+    }
+
+    #[test]
+    fn observable_compare_in_both_directions_concurrently() {
+        let first = Arc::new(Observable::new(1u8, TEST_EVENT_NAME));
+        let second = Arc::new(Observable::new(1u8, TEST_EVENT_NAME));
+        assert!(*first == *first);
+
+        let threads: Vec<_> = [(first.clone(), second.clone()), (second, first)]
+            .into_iter()
+            .map(|(left, right)| std::thread::spawn(move || (0..10_000).all(|_| *left == *right)))
+            .collect();
+        for thread in threads {
+            assert!(thread.join().unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn observable_set_through_shared_reference() {
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 10, false, false)
+            .unwrap();
+
+        let tasks: Vec<_> = (1..=3)
+            .map(|value| {
+                let observable = observable.clone();
+                tokio::spawn(async move { observable.set(value).await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let mut received = Vec::new();
+        while let Ok(value) = receiver.try_recv() {
+            received.push(value);
+        }
+
+        // Every set is dispatched once, in the order the values were applied in
+        assert_eq!(received.last(), Some(&observable.get()));
+        received.sort();
+        assert_eq!(received, [1, 2, 3]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn observable_dispatches_concurrent_changes_in_order() {
+        let observable = Arc::new(Observable::new(0u32, TEST_EVENT_NAME));
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let closure_received = received.clone();
+        observable
+            .on_change
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |value| {
+                    closure_received.lock().unwrap().push(value);
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let observable = observable.clone();
+                tokio::spawn(async move {
+                    for _ in 0..500 {
+                        observable.update(|value| *value += 1).await;
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(*received.lock().unwrap(), (1..=2000).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn observable_dispatches_after_cancelled_dispatch() {
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        observable
+            .on_change
+            .subscribe_async_closure(
+                TEST_CLOSURE_NAME,
+                |value| {
+                    Box::pin(async move {
+                        if value == 1 {
+                            std::future::pending::<()>().await;
+                        }
+                        Ok(())
+                    })
+                },
+                false,
+                false,
+            )
+            .unwrap();
+
+        let stuck = {
+            let observable = observable.clone();
+            tokio::spawn(async move { observable.set(1).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        stuck.abort();
+
+        let result = tokio::time::timeout(Duration::from_secs(1), observable.set(2)).await;
+        assert!(matches!(result, Ok(observable::Result::Changed(Ok(())))));
+    }
+
+    #[tokio::test]
+    async fn mapped_observable_follows_source() {
+        let observable = Observable::new((1u8, TEST_DATA_INITIAL), TEST_EVENT_NAME);
+        let name = observable.map(|(_, name)| *name).unwrap();
+        assert_eq!(name.get(), TEST_DATA_INITIAL);
+
+        let (_, mut receiver) = name
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        observable.set((2, TEST_DATA_INITIAL)).await;
+        observable.set((2, TEST_DATA)).await;
+
+        assert_eq!(receiver.recv().await, Some(TEST_DATA));
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(name, TEST_DATA);
+    }
+
+    #[tokio::test]
+    async fn computed_observable_follows_all_sources() {
+        let width = Observable::new(2u32, TEST_EVENT_NAME);
+        let height = Observable::new(3u32, TEST_EVENT_NAME);
+        let area = Observable::computed((&width, &height), |width, height| width * height).unwrap();
+        assert_eq!(area.get(), 6);
+
+        let (_, mut receiver) = area
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        width.set(4).await;
+        assert_eq!(receiver.recv().await, Some(12));
+
+        height.set(5).await;
+        assert_eq!(receiver.recv().await, Some(20));
+        assert_eq!(area, 20);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn derived_observables_built_during_changes_follow_source() {
+        let observable = Arc::new(Observable::new(0u32, TEST_EVENT_NAME));
+        let setter = {
+            let observable = observable.clone();
+            tokio::spawn(async move {
+                for _ in 0..200 {
+                    observable.update(|value| *value += 1).await;
+                }
+            })
+        };
+
+        let mut mapped = Vec::new();
+        let mut computed = Vec::new();
+        for _ in 0..20 {
+            mapped.push(observable.map(|value| *value).unwrap());
+            computed
+                .push(Observable::computed((&*observable, &*observable), |a, b| a + b).unwrap());
+            tokio::task::yield_now().await;
+        }
+        setter.await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let value = observable.get();
+        assert!(mapped.iter().all(|mapped| mapped.get() == value));
+        assert!(computed.iter().all(|computed| computed.get() == value * 2));
+    }
+
+    #[tokio::test]
+    async fn filtered_observable_keeps_last_accepted_value() {
+        let observable = Observable::new(2u8, TEST_EVENT_NAME);
+        let even = observable.filtered(0, |value| value % 2 == 0).unwrap();
+
+        observable.set(3).await;
+        assert_eq!(even, 2);
+
+        observable.set(4).await;
+        assert_eq!(even, 4);
+    }
+
+    #[test]
+    fn filtered_observable_starts_with_fallback() {
+        let observable = Observable::new(3u8, TEST_EVENT_NAME);
+        let even = observable.filtered(0, |value| value % 2 == 0).unwrap();
+
+        assert_eq!(even, 0);
+    }
+
+    #[tokio::test]
+    async fn debounced_observable_settles_to_last_value() {
+        let observable = Observable::new(0u8, TEST_EVENT_NAME);
+        let debounced = observable.debounced(Duration::from_millis(50)).unwrap();
+
+        let (_, mut receiver) = debounced
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        for value in 1..=3 {
+            observable.set(value).await;
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(debounced, 0);
+
+        assert_eq!(receiver.recv().await, Some(3));
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(debounced, 3);
+    }
+
+    #[tokio::test]
+    async fn throttled_observable_conflates_values() {
+        let observable = Observable::new(0u8, TEST_EVENT_NAME);
+        let throttled = observable.throttled(Duration::from_millis(50)).unwrap();
+
+        let (_, mut receiver) = throttled
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        for value in 1..=3 {
+            observable.set(value).await;
+        }
+
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(3));
+        assert_eq!(throttled, 3);
+    }
+
+    #[tokio::test]
+    async fn sampled_observable_snapshots_latest_value() {
+        let observable = Observable::new(0u8, TEST_EVENT_NAME);
+        let sampled = observable.sampled(Duration::from_millis(50)).unwrap();
+
+        let (_, mut receiver) = sampled
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        for value in 1..=3 {
+            observable.set(value).await;
+        }
+        assert_eq!(sampled, 0);
+
+        assert_eq!(receiver.recv().await, Some(3));
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn transaction_dispatches_once() {
+        let observable = Observable::new((0u8, 0u8), TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        let result = observable
+            .transaction(|transaction| {
+                transaction.set((1, 0));
+                transaction.update(|(_, second)| *second = 2);
+            })
+            .await;
+        assert!(matches!(result, observable::Result::Changed(Ok(()))));
+
+        let result = observable
+            .transaction(|transaction| {
+                transaction.set((5, 5));
+                transaction.set((1, 2));
+            })
+            .await;
+        assert!(matches!(result, observable::Result::Unchanged));
+
+        assert_eq!(receiver.recv().await, Some((1, 2)));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn validator_rejects_invalid_values() {
+        let observable = Observable::new(1u8, TEST_EVENT_NAME);
+        observable.set_validator(|value| match value {
+            0 => Err("value must not be zero".into()),
+            _ => Ok(()),
+        });
+
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        let result = observable.set(0).await;
+        assert!(matches!(result, observable::Result::Rejected(_)));
+
+        let result = observable.update(|value| *value -= 1).await;
+        assert!(matches!(result, observable::Result::Rejected(_)));
+        assert_eq!(observable, 1);
+
+        observable.set(2).await;
+        assert_eq!(receiver.recv().await, Some(2));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn undo_and_redo_restore_history() {
+        let observable = Observable::new(0u8, TEST_EVENT_NAME);
+        observable.enable_history(2);
+
+        for value in 1..=3 {
+            observable.set(value).await;
+        }
+
+        observable.undo().await;
+        observable.undo().await;
+        assert_eq!(observable, 1);
+        assert!(!observable.can_undo());
+        assert!(matches!(
+            observable.undo().await,
+            observable::Result::Unchanged
+        ));
+
+        observable.redo().await;
+        assert_eq!(observable, 2);
+
+        observable.set(5).await;
+        assert!(!observable.can_redo());
+        observable.undo().await;
+        assert_eq!(observable, 2);
+    }
+
+    #[tokio::test]
+    async fn watch_receiver_starts_with_current_value() {
+        let observable = Observable::new(1u8, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable.subscribe_watch(TEST_CLOSURE_NAME).unwrap();
+        assert_eq!(*receiver.borrow_and_update(), 1);
+
+        observable.set(2).await;
+        receiver.changed().await.unwrap();
+        assert_eq!(*receiver.borrow_and_update(), 2);
+
+        drop(receiver);
+        observable.set(3).await;
+        assert_eq!(observable.on_change.subscriber_count(), 0);
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn changes_stream_yields_new_values() {
+        use futures_util::StreamExt;
+
+        let observable = Observable::new(0u8, TEST_EVENT_NAME);
+        let changes = observable.changes().unwrap();
+
+        observable.set(1).await;
+        observable.set(2).await;
+
+        let values: Vec<_> = changes.take(2).collect().await;
+        assert_eq!(values, [1, 2]);
+
+        observable.set(3).await;
+        assert_eq!(observable.on_change.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn async_observable_dispatches_initial_value() {
+        let observable = Observable::new_async(0, async { 5u8 }, TEST_EVENT_NAME);
+        assert!(!observable.is_ready());
+        assert_eq!(*observable, 0);
+
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        assert_eq!(observable.get_when_ready().await, 5);
+        assert!(observable.is_ready());
+        assert_eq!(receiver.recv().await, Some(5));
+
+        observable.ready().await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn async_observable_keeps_value_set_before_ready() {
+        let observable = Observable::new_async(0, async { 5u8 }, TEST_EVENT_NAME);
+        observable.enable_history(2);
+        observable.set(7).await;
+
+        assert_eq!(observable.get_when_ready().await, 7);
+        observable.undo().await;
+        assert_eq!(*observable, 0);
+    }
+
+    #[tokio::test]
+    async fn async_observable_applies_initial_value_without_ready() {
+        let observable = Observable::new_async(0, async { 5u8 }, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        assert_eq!(receiver.recv().await, Some(5));
+        assert_eq!(*observable, 5);
+    }
+
+    #[tokio::test]
+    async fn version_counts_changes() {
+        let observable = Observable::new(0u8, TEST_EVENT_NAME);
+        let (version, value) = observable.get_versioned();
+        assert_eq!((version, value), (0, 0));
+
+        observable.set(0).await;
+        assert!(!observable.changed_since(version));
+
+        observable.set(1).await;
+        observable.update(|value| *value += 1).await;
+        assert!(observable.changed_since(version));
+        assert_eq!(observable.get_versioned(), (2, 2));
+    }
+
+    #[tokio::test]
+    async fn observe_values_without_partial_eq() {
+        #[derive(Clone)]
+        struct Handle(Arc<dyn Fn() -> u8 + Send + Sync>);
+
+        let observable = Observable::with_change_detection(
+            Handle(Arc::new(|| 1)),
+            TEST_EVENT_NAME,
+            ChangeDetection::Always,
+        );
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        let result = observable.set(observable.get()).await;
+        assert!(matches!(result, observable::Result::Changed(Ok(()))));
+
+        let handle = receiver.recv().await.unwrap();
+        assert_eq!((handle.0)(), 1);
+    }
+
+    #[tokio::test]
+    async fn comparator_ignores_fields() {
+        // (value, timestamp)
+        let observable =
+            Observable::with_comparator((1u8, 100u64), TEST_EVENT_NAME, |current, value| {
+                current.0 == value.0
+            });
+
+        let result = observable.set((1, 200)).await;
+        assert!(matches!(result, observable::Result::Unchanged));
+        assert_eq!(observable.get(), (1, 100));
+
+        let result = observable.set((2, 300)).await;
+        assert!(matches!(result, observable::Result::Changed(Ok(()))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_delegates_to_value() {
+        let observable = Observable::new(3u8, TEST_EVENT_NAME);
+        let json = serde_json::to_string(&observable).unwrap();
+        assert_eq!(json, "3");
+
+        let deserialized: Observable<u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, 3);
+
+        let mut deserializer = serde_json::Deserializer::from_str("\"value\"");
+        let arc_observable =
+            ArcObservable::<String>::deserialize_named(&mut deserializer, TEST_EVENT_NAME).unwrap();
+        assert_eq!(arc_observable.on_change.name(), TEST_EVENT_NAME);
+        assert_eq!(serde_json::to_string(&arc_observable).unwrap(), "\"value\"");
+    }
+
+    #[tokio::test]
+    async fn default_and_into_inner() {
+        let observable = Observable::<u8>::default();
+        observable.set(2).await;
+        assert_eq!(observable.into_inner(), 2);
+
+        let arc_observable = ArcObservable::<u8>::default();
+        arc_observable.set(3).await;
+        assert_eq!(*arc_observable.into_inner(), 3);
+    }
+
+    #[tokio::test]
+    async fn observable_driven_by_event() {
+        let event = Event::<u8>::new(TEST_EVENT_NAME);
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        observable.driven_by(&event).unwrap();
+
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        event.dispatch(4).await.unwrap();
+        assert_eq!(receiver.recv().await, Some(4));
+        assert_eq!(*observable, 4);
+
+        drop(observable);
+        event.dispatch(5).await.unwrap();
+        assert_eq!(event.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn reader_observes_without_setting() {
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        let reader = observable.reader();
+        assert_eq!(reader.get(), 0);
+
+        let (_, mut receiver) = reader
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+
+        let changed_reader = reader.clone();
+        let changed = tokio::spawn(async move { changed_reader.changed().await });
+        tokio::task::yield_now().await;
+
+        observable.set(1).await;
+        assert_eq!(changed.await.unwrap(), 1);
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(reader, 1);
+    }
+
+    #[tokio::test]
+    async fn split_into_writer_and_readers() {
+        let (writer, reader) = Observable::new(0u8, TEST_EVENT_NAME).split();
+        let second_reader = reader.clone();
+
+        writer.set(1).await;
+        writer.update(|value| *value += 1).await;
+
+        assert_eq!(reader, 2);
+        assert_eq!(second_reader, 2);
+        assert_eq!(writer.reader().get(), 2);
+    }
+
+    #[tokio::test]
+    async fn subscribe_with_current_value() {
+        let observable = Observable::new(1u8, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .subscribe_channel(TEST_CLOSURE_NAME, 2, true, false, false)
+            .unwrap();
+
+        let calls = Arc::new(AtomicU8::new(0));
+        let closure_calls = calls.clone();
+        observable
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |value| {
+                    closure_calls.fetch_add(value, Ordering::Relaxed);
+                    Ok(())
+                },
+                true,
+                false,
+                false,
+            )
+            .unwrap();
+
+        observable.set(2).await;
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn subscribe_closure_delivers_current_value_before_changes() {
+        let observable = Arc::new(Observable::new(0u32, TEST_EVENT_NAME));
+        let stop = Arc::new(AtomicBool::new(false));
+        let setter = {
+            let observable = observable.clone();
+            let stop = stop.clone();
+            tokio::spawn(async move {
+                let mut value = 0;
+                while !stop.load(Ordering::Relaxed) {
+                    value += 1;
+                    observable.set(value).await;
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let closure_received = received.clone();
+        let first = AtomicBool::new(true);
+        observable
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |value| {
+                    // A slow first delivery gives concurrent changes the chance to overtake it
+                    if first.swap(false, Ordering::Relaxed) {
+                        std::thread::sleep(Duration::from_millis(20));
+                    }
+                    closure_received.lock().unwrap().push(value);
+                    Ok(())
+                },
+                true,
+                false,
+                false,
+            )
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        stop.store(true, Ordering::Relaxed);
+        setter.await.unwrap();
+
+        // A change that was stored but not yet dispatched when subscribing repeats the current value
+        let mut received = received.lock().unwrap().clone();
+        received.dedup();
+        assert_eq!(
+            received,
+            (received[0]..=observable.get()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn subscribe_closure_lets_changes_through_during_first_delivery() {
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let closure_received = received.clone();
+        let closure_observable = observable.clone();
+        let runtime = tokio::runtime::Handle::current();
+        observable
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |value| {
+                    if value == 0 {
+                        // Waits for a change to be dispatched while the current value is delivered
+                        let (sender, receiver) = std::sync::mpsc::channel();
+                        let observable = closure_observable.clone();
+                        let runtime = runtime.clone();
+                        std::thread::spawn(move || {
+                            runtime.block_on(observable.set(1));
+                            let _ = sender.send(());
+                        });
+                        assert!(receiver.recv_timeout(Duration::from_secs(1)).is_ok());
+                    }
+                    closure_received.lock().unwrap().push(value);
+                    Ok(())
+                },
+                true,
+                false,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(*received.lock().unwrap(), [0, 1]);
+    }
+
+    #[tokio::test]
+    async fn expiring_value_reverts() {
+        let observable = Arc::new(Observable::new(None, TEST_EVENT_NAME));
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        observable
+            .set_expiring(Some("copied"), Duration::from_millis(20), None)
+            .await;
+        assert_eq!(receiver.recv().await, Some(Some("copied")));
+        assert_eq!(receiver.recv().await, Some(None));
+
+        observable
+            .set_expiring(Some("copied"), Duration::from_millis(20), None)
+            .await;
+        observable.set(Some("error")).await;
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(*observable, Some("error"));
+    }
+
+    #[tokio::test]
+    async fn expiring_value_restarts_ttl_when_set_again() {
+        let observable = Arc::new(Observable::new(None, TEST_EVENT_NAME));
+        let ttl = Duration::from_millis(100);
+
+        observable.set_expiring(Some("copied"), ttl, None).await;
+        tokio::time::sleep(ttl / 2).await;
+        observable.set_expiring(Some("copied"), ttl, None).await;
+
+        tokio::time::sleep(ttl * 7 / 10).await;
+        assert_eq!(*observable, Some("copied"));
+
+        tokio::time::sleep(ttl).await;
+        assert_eq!(*observable, None);
+    }
+
+    #[tokio::test]
+    async fn expiring_value_keeps_revert_of_rejected_set() {
+        let observable = Arc::new(Observable::new(None, TEST_EVENT_NAME));
+        observable.set_validator(|value| match value {
+            Some("invalid") => Err("Invalid value".into()),
+            _ => Ok(()),
+        });
+        let ttl = Duration::from_millis(100);
+
+        observable.set_expiring(Some("copied"), ttl, None).await;
+        tokio::time::sleep(ttl / 2).await;
+        let result = observable.set_expiring(Some("invalid"), ttl, None).await;
+        assert!(matches!(result, observable::Result::Rejected(_)));
+
+        tokio::time::sleep(ttl * 7 / 10).await;
+        assert_eq!(*observable, None);
+    }
+
+    #[tokio::test]
+    async fn expiring_value_skips_rejected_revert() {
+        let observable = Arc::new(Observable::new(None, TEST_EVENT_NAME));
+        let ttl = Duration::from_millis(50);
+
+        observable.set_expiring(Some("copied"), ttl, None).await;
+        observable.set_validator(|value| match value {
+            None => Err("Missing value".into()),
+            _ => Ok(()),
+        });
+
+        tokio::time::sleep(ttl * 2).await;
+        assert_eq!(*observable, Some("copied"));
+    }
+
+    #[tokio::test]
+    async fn wait_for_matching_value() {
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        assert_eq!(observable.wait_for(|value| *value == 0).await, 0);
+
+        let waiting_observable = observable.clone();
+        let waiter =
+            tokio::spawn(async move { waiting_observable.wait_for(|value| *value >= 2).await });
+
+        for value in 1..=3 {
+            observable.set(value).await;
+            tokio::task::yield_now().await;
+        }
+
+        assert!(waiter.await.unwrap() >= 2);
+    }
+
+    #[tokio::test]
+    async fn changed_resolves_on_next_change() {
+        let observable = Arc::new(Observable::new(0u8, TEST_EVENT_NAME));
+        let waiting_observable = observable.clone();
+        let changed = tokio::spawn(async move { waiting_observable.changed().await });
+        tokio::task::yield_now().await;
+
+        observable.set(0).await;
+        observable.set(1).await;
+        assert_eq!(changed.await.unwrap(), 1);
+        assert_eq!(observable.on_change.subscriber_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn get_and_subscribe_channel_atomically() {
+        let observable = Observable::new(1u8, TEST_EVENT_NAME);
+        let (value, _, mut receiver) = observable
+            .get_and_subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+        assert_eq!(value, 1);
+
+        observable.set(2).await;
+        assert_eq!(receiver.recv().await, Some(2));
+
+        let arc_observable = ArcObservable::new(1u8, TEST_EVENT_NAME);
+        let (value, _, mut receiver) = arc_observable
+            .get_and_subscribe_channel(TEST_CLOSURE_NAME, 2, false, false)
+            .unwrap();
+        assert_eq!(*value, 1);
+
+        arc_observable.set(2).await;
+        assert_eq!(receiver.recv().await.as_deref(), Some(&2));
+    }
+
+    #[tokio::test]
+    async fn numeric_helpers_return_new_value() {
+        let observable = Observable::new(250u8, TEST_EVENT_NAME);
+        let (_, mut receiver) = observable
+            .on_change
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        assert_eq!(observable.incr().await.0, 251);
+        assert_eq!(observable.saturating_add(10).await.0, 255);
+        assert!(matches!(
+            observable.saturating_add(10).await,
+            (255, observable::Result::Unchanged)
+        ));
+        assert_eq!(observable.sub(5).await.0, 250);
+
+        assert_eq!(receiver.recv().await, Some(251));
+        assert_eq!(receiver.recv().await, Some(255));
+        assert_eq!(receiver.recv().await, Some(250));
+        assert!(receiver.try_recv().is_err());
+
+        let concurrent = Arc::new(Observable::new(0u64, TEST_EVENT_NAME));
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let concurrent = concurrent.clone();
+                tokio::spawn(async move { concurrent.incr().await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(*concurrent, 10);
+    }
+
+    #[tokio::test]
+    async fn numeric_helpers_handle_overflow() {
+        let observable = Observable::new(250u8, TEST_EVENT_NAME);
+
+        assert!(observable.checked_add(10).await.is_none());
+        assert_eq!(observable.get(), 250);
+        assert_eq!(
+            observable.checked_add(5).await.map(|(value, _)| value),
+            Some(255)
+        );
+        assert_eq!(observable.add(2).await.0, 1);
+        assert!(observable.checked_sub(2).await.is_none());
+        assert_eq!(observable.decr().await.0, 0);
+        assert_eq!(observable.decr().await.0, 255);
+
+        let float = Observable::new(0.5f64, TEST_EVENT_NAME);
+        assert_eq!(float.add(1.0).await.0, 1.5);
+    }
+
+    #[tokio::test]
+    async fn numeric_helpers_report_rejected_values() {
+        let observable = Observable::new(1u8, TEST_EVENT_NAME);
+        observable.set_validator(|value| match value {
+            0..=9 => Ok(()),
+            _ => Err("Too large".into()),
+        });
+
+        assert!(matches!(
+            observable.checked_add(10).await,
+            Some((1, observable::Result::Rejected(_)))
+        ));
+        assert!(matches!(
+            observable.incr().await,
+            (2, observable::Result::Changed(Ok(())))
+        ));
+        assert_eq!(observable.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn option_helpers() {
+        let observable = Arc::new(Observable::new(None, TEST_EVENT_NAME));
+        assert!(observable.is_none());
+
+        let waiting_observable = observable.clone();
+        let when_some = tokio::spawn(async move { waiting_observable.when_some().await });
+        tokio::task::yield_now().await;
+
+        observable.set_some(3u8).await;
+        assert_eq!(when_some.await.unwrap(), 3);
+        assert!(observable.is_some());
+
+        assert_eq!(observable.take().await, Some(3));
+        assert_eq!(observable.take().await, None);
+        assert!(observable.is_none());
+    }
+
+    #[tokio::test]
+    async fn lens_reads_and_writes_field() {
+        let observable = Arc::new(Observable::new((1u8, 10u8), TEST_EVENT_NAME));
+        let lens = observable
+            .lens(|(first, _)| first, |(first, _), value| *first = value)
+            .unwrap();
+        assert_eq!(lens.get(), 1);
+
+        let (_, mut receiver) = lens
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        observable.set((1, 20)).await;
+        lens.set(2).await;
+        lens.set(2).await;
+
+        assert_eq!(*observable, (2, 20));
+        assert_eq!(receiver.recv().await, Some(2));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn distinct_until_key_changes() {
+        // (id, payload)
+        let observable = Observable::new((1u8, 0u8), TEST_EVENT_NAME);
+        let distinct = observable.distinct_until_changed_by(|(id, _)| *id).unwrap();
+
+        let (_, mut receiver) = distinct
+            .subscribe_channel(TEST_CLOSURE_NAME, 4, false, false)
+            .unwrap();
+
+        observable.set((1, 1)).await;
+        observable.set((2, 2)).await;
+        observable.set((2, 3)).await;
+
+        assert_eq!(receiver.recv().await, Some((2, 2)));
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(distinct.get(), (2, 2));
+    }
+
     //TODO: This should check the observable and the value for equality, not the inside value
     //TODO: This is a unit test. Move to arc_observable.rs
     #[test]
@@ -80,16 +1090,19 @@ mod tests {
         let count = Arc::new(AtomicU8::new(0));
 
         let count_clone = count.clone();
-        let id = observable.on_change.subscribe_closure(
-            TEST_CLOSURE_NAME,
-            move |data| {
-                assert_eq!(*data, TEST_DATA);
-                count_clone.fetch_add(1, Ordering::Relaxed);
-                Ok(())
-            },
-            false,
-            false,
-        );
+        let id = observable
+            .on_change
+            .subscribe_closure(
+                TEST_CLOSURE_NAME,
+                move |data| {
+                    assert_eq!(*data, TEST_DATA);
+                    count_clone.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                },
+                false,
+                false,
+            )
+            .unwrap();
         assert_eq!(observable.on_change.subscriber_count(), 1);
         assert_eq!(count.load(Ordering::Relaxed), 0);
 